@@ -0,0 +1,95 @@
+use std::fmt;
+
+/// Error codes given by the API.
+///
+/// torrentapi documents its parameters at <https://torrentapi.org/apidocs_v2.txt> but not its
+/// error codes. The codes below are mapped from the messages the API sends with them, which
+/// are quoted for each code. Codes without a known message, like 3, are kept as `Unknown`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ApiErrorCode {
+    /// No token was sent with the request: "No token set!" (code 1).
+    NoTokenSet,
+    /// The token sent with the request is not known by the API: "Invalid token set!" (code 2).
+    InvalidToken,
+    /// The token sent with the request has expired: "Invalid token. Use get_token for a new
+    /// one!" (code 4).
+    TokenExpired,
+    /// The 1req/2s limit has been exceeded: "Too many requests per second. Maximum requests
+    /// allowed are 1req/2sec Please try again later!" (code 5).
+    TooManyRequests,
+    /// The IMDB id sent with the request is not known by the API: "Cant find imdb in
+    /// database. Are you sure this imdb exists?" (code 10).
+    ImdbNotFound,
+    /// The search or the list did not match any torrent: "No results found" (code 20).
+    NoResults,
+    /// A code that this crate does not know yet.
+    Unknown(u8),
+}
+
+impl ApiErrorCode {
+    /// Return the numeric code given by the API.
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            ApiErrorCode::NoTokenSet => 1,
+            ApiErrorCode::InvalidToken => 2,
+            ApiErrorCode::TokenExpired => 4,
+            ApiErrorCode::TooManyRequests => 5,
+            ApiErrorCode::ImdbNotFound => 10,
+            ApiErrorCode::NoResults => 20,
+            ApiErrorCode::Unknown(code) => *code,
+        }
+    }
+
+    /// Return true if the search or the list did not match any torrent, otherwise false.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::api_error_code::ApiErrorCode;
+    ///
+    /// assert!(ApiErrorCode::from(20).is_no_results());
+    /// ```
+    pub fn is_no_results(&self) -> bool {
+        *self == ApiErrorCode::NoResults
+    }
+
+    /// Return true if the token must be refreshed before retrying, otherwise false.
+    pub fn is_invalid_token(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorCode::NoTokenSet | ApiErrorCode::InvalidToken | ApiErrorCode::TokenExpired
+        )
+    }
+
+    /// Return true if the same request may succeed later, otherwise false.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::api_error_code::ApiErrorCode;
+    ///
+    /// assert!(ApiErrorCode::TooManyRequests.is_retryable());
+    /// assert!(!ApiErrorCode::ImdbNotFound.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        *self == ApiErrorCode::TooManyRequests
+    }
+}
+
+impl From<u8> for ApiErrorCode {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => ApiErrorCode::NoTokenSet,
+            2 => ApiErrorCode::InvalidToken,
+            4 => ApiErrorCode::TokenExpired,
+            5 => ApiErrorCode::TooManyRequests,
+            10 => ApiErrorCode::ImdbNotFound,
+            20 => ApiErrorCode::NoResults,
+            code => ApiErrorCode::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_u8())
+    }
+}
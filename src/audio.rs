@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// An audio codec or format of a release.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Audio {
+    Mp3,
+    Aac,
+    Opus,
+    Ac3,
+    Eac3,
+    Dts,
+    DtsHd,
+    TrueHd,
+    Atmos,
+    Flac,
+}
+
+impl Audio {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Audio::Mp3 => "MP3",
+            Audio::Aac => "AAC",
+            Audio::Opus => "Opus",
+            Audio::Ac3 => "DD",
+            Audio::Eac3 => "DDP",
+            Audio::Dts => "DTS",
+            Audio::DtsHd => "DTS-HD",
+            Audio::TrueHd => "TrueHD",
+            Audio::Atmos => "Atmos",
+            Audio::Flac => "FLAC",
+        }
+    }
+
+    /// Channels are ignored, so "DDP5" or "AAC2" are recognized.
+    pub(crate) fn from_token(token: &str) -> Option<Self> {
+        let lowercase = token.to_ascii_lowercase();
+        Audio::from_name(lowercase.as_str())
+            .or_else(|| Audio::from_name(lowercase.trim_end_matches(|c: char| c.is_ascii_digit())))
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "mp3" => Some(Audio::Mp3),
+            "aac" => Some(Audio::Aac),
+            "opus" => Some(Audio::Opus),
+            "ac3" | "dd" => Some(Audio::Ac3),
+            "eac3" | "ddp" | "dd+" => Some(Audio::Eac3),
+            "dts" => Some(Audio::Dts),
+            "dts-hd" | "dtshd" | "dts-x" | "dtsx" => Some(Audio::DtsHd),
+            "truehd" => Some(Audio::TrueHd),
+            "atmos" => Some(Audio::Atmos),
+            "flac" => Some(Audio::Flac),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::api_parameters::ApiParameters;
+use crate::config::Config;
+use crate::imdb_id::ImdbId;
+use crate::rarbg_api_builder::RarBgApiBuilder;
+use crate::rarbg_error::RarBgError;
+use crate::rate_limiter::RateLimiter;
+use crate::search_id::SearchId;
+use crate::search_query::SearchQuery;
+use crate::tmdb_id::TmdbId;
+use crate::token::Token;
+use crate::token_store::TokenStore;
+use crate::torrents::Torrents;
+use crate::tvdb_id::TvdbId;
+use crate::RarBgApi as AsyncRarBgApi;
+
+/// A blocking RARBG client.
+///
+/// It drives an asynchronous `RarBgApi` on a runtime of its own, so it shares the same
+/// configuration, retries and token handling.
+///
+/// # Panics
+///
+/// Its methods panic if they are called from within an asynchronous runtime.
+#[derive(Debug)]
+pub struct RarBgApi {
+    inner: AsyncRarBgApi,
+    runtime: Runtime,
+}
+
+impl RarBgApi {
+    /// Return the name of your app.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// let app_id = api.app_id();
+    /// ```
+    pub fn app_id(&self) -> &str {
+        self.inner.app_id()
+    }
+
+    /// Return the token associate to your app.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    /// use rarbg_api::token::Token;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// let token: Token = api.token();
+    /// ```
+    pub fn token(&self) -> Token {
+        self.inner.token()
+    }
+
+    /// Return the configuration of the client.
+    pub fn config(&self) -> &Config {
+        self.inner.config()
+    }
+
+    /// Return the rate limiter used by the client.
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        self.inner.rate_limiter()
+    }
+
+    /// Create a new blocking RARBG client with the default configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - A string slice that holds the name of your app.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a token cannot be retrieve from the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// ```
+    pub fn new(app_id: &str) -> Result<Self, RarBgError> {
+        RarBgApi::with_builder(&RarBgApiBuilder::new(), app_id)
+    }
+
+    /// Create a new blocking RARBG client that loads its token from the given store, and saves
+    /// there every new token retrieved from the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started or if a token cannot be retrieve from the
+    /// API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use rarbg_api::blocking::RarBgApi;
+    /// use rarbg_api::file_token_store::FileTokenStore;
+    ///
+    /// let store = Arc::new(FileTokenStore::new("/tmp/rarbg_tokens.json"));
+    /// let api = RarBgApi::with_token_store("RustExample", store).unwrap();
+    /// ```
+    pub fn with_token_store(
+        app_id: &str,
+        token_store: Arc<dyn TokenStore>,
+    ) -> Result<Self, RarBgError> {
+        RarBgApi::with_builder(RarBgApiBuilder::new().token_store(token_store), app_id)
+    }
+
+    /// Create a new blocking RARBG client configured by a RarBgApiBuilder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started, if the proxy is invalid or if a token
+    /// cannot be retrieve from the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::blocking::RarBgApi;
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let mut builder = RarBgApiBuilder::new();
+    /// builder.request_delay(Duration::from_secs(2));
+    /// let api = RarBgApi::with_builder(&builder, "RustExample").unwrap();
+    /// ```
+    pub fn with_builder(builder: &RarBgApiBuilder, app_id: &str) -> Result<Self, RarBgError> {
+        let runtime = match Builder::new_current_thread().enable_all().build() {
+            Ok(runtime) => runtime,
+            Err(reason) => return Err(RarBgError::Runtime(reason)),
+        };
+        let inner = runtime.block_on(builder.build(app_id))?;
+        Ok(RarBgApi { inner, runtime })
+    }
+
+    /// List the torrents available depending on parameters given.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// // It will get the 25 last ranked torrents
+    /// let result = api.list(None);
+    /// ```
+    pub fn list(&self, parameters: Option<&ApiParameters>) -> Result<Torrents, RarBgError> {
+        self.runtime.block_on(self.inner.list(parameters))
+    }
+
+    /// Search torrents matching a SearchQuery with some or no parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RarBgError::InvalidQuery` without sending anything if the query is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    /// use rarbg_api::search_id::SearchId;
+    /// use rarbg_api::search_query::SearchQuery;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// // tt2861424 is Rick and Morty
+    /// let query = SearchQuery::new()
+    ///     .with_id(SearchId::Imdb("tt2861424".parse().unwrap()))
+    ///     .with_string("S02E03");
+    /// let result = api.query(&query, None);
+    /// ```
+    pub fn query(
+        &self,
+        query: &SearchQuery,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.runtime.block_on(self.inner.query(query, parameters))
+    }
+
+    /// Search torrents by its name with some or no parameters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// let result = api.search("Rick and Morty", None);
+    /// ```
+    pub fn search(
+        &self,
+        value: &str,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.runtime.block_on(self.inner.search(value, parameters))
+    }
+
+    /// Search torrents by its IMDB id with some or no parameters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// // tt2861424 is Rick and Morty
+    /// let result = api.search_by_imdb(&"tt2861424".parse().unwrap(), None);
+    /// ```
+    pub fn search_by_imdb(
+        &self,
+        value: &ImdbId,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.runtime
+            .block_on(self.inner.search_by_imdb(value, parameters))
+    }
+
+    /// Search torrents by its TVDB id with some or no parameters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// // 275274 is Rick and Morty
+    /// let result = api.search_by_tvdb(&"275274".parse().unwrap(), None);
+    /// ```
+    pub fn search_by_tvdb(
+        &self,
+        value: &TvdbId,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.runtime
+            .block_on(self.inner.search_by_tvdb(value, parameters))
+    }
+
+    /// Search torrents by its TMDB id with some or no parameters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// // 60625 is Rick and Morty
+    /// let result = api.search_by_tmdb(&"60625".parse().unwrap(), None);
+    /// ```
+    pub fn search_by_tmdb(
+        &self,
+        value: &TmdbId,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.runtime
+            .block_on(self.inner.search_by_tmdb(value, parameters))
+    }
+
+    /// Search the torrents of an episode, or of a whole season if `episode` is None, of a TV show.
+    ///
+    /// See `rarbg_api::RarBgApi::search_episode`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::blocking::RarBgApi;
+    /// use rarbg_api::search_id::SearchId;
+    ///
+    /// let api = RarBgApi::new("RustExample").unwrap();
+    /// // tt2861424 is Rick and Morty
+    /// let show = SearchId::Imdb("tt2861424".parse().unwrap());
+    /// let result = api.search_episode(&show, 3, Some(7), None);
+    /// ```
+    pub fn search_episode(
+        &self,
+        show: &SearchId,
+        season: u32,
+        episode: Option<u32>,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.runtime
+            .block_on(self.inner.search_episode(show, season, episode, parameters))
+    }
+}
@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::interaction::Interaction;
+use crate::rarbg_error::RarBgError;
+
+/// Requests sent to the API with their responses, recorded by a `RecordingTransport` and
+/// replayed by a `ReplayingTransport`.
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+pub struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Create an empty Cassette.
+    pub fn new() -> Self {
+        Cassette::default()
+    }
+
+    /// Read a Cassette from a JSON file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::cassette::Cassette;
+    ///
+    /// let cassette = Cassette::load("tests/cassettes/search.json").unwrap();
+    /// ```
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RarBgError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(reason) => return Err(RarBgError::Cassette(Box::new(reason))),
+        };
+        match serde_json::from_str(content.as_str()) {
+            Ok(cassette) => Ok(cassette),
+            Err(reason) => Err(RarBgError::Cassette(Box::new(reason))),
+        }
+    }
+
+    /// Write the Cassette to a JSON file, replacing it if it exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), RarBgError> {
+        let content = match serde_json::to_string_pretty(self) {
+            Ok(content) => content,
+            Err(reason) => return Err(RarBgError::Cassette(Box::new(reason))),
+        };
+        match fs::write(path, content) {
+            Ok(_) => Ok(()),
+            Err(reason) => Err(RarBgError::Cassette(Box::new(reason))),
+        }
+    }
+
+    /// Append an interaction.
+    pub fn push(&mut self, interaction: Interaction) {
+        self.interactions.push(interaction);
+    }
+
+    /// Return the interactions in the order they were recorded.
+    pub fn interactions(&self) -> &Vec<Interaction> {
+        &self.interactions
+    }
+}
@@ -3,6 +3,38 @@ extern crate std;
 
 use serde::{Deserialize, Serialize};
 
+use crate::category_group::CategoryGroup;
+use crate::resolution::Resolution;
+use crate::video_codec::VideoCodec;
+
+const ALL: [Category; 25] = [
+    Category::Xxx,
+    Category::MoviesXvid,
+    Category::MoviesXvid720,
+    Category::MoviesX264,
+    Category::MoviesX2641080,
+    Category::MoviesX264720,
+    Category::MoviesX2643d,
+    Category::MoviesX2644k,
+    Category::MoviesX2651080,
+    Category::MoviesX2654k,
+    Category::MoviesX2654kHdr,
+    Category::MoviesFullBd,
+    Category::MoviesBdRemux,
+    Category::TvEpisodes,
+    Category::TvHdEpisodes,
+    Category::TvUhdEpisodes,
+    Category::MusicMp3,
+    Category::MusicFlac,
+    Category::GamesPcIso,
+    Category::GamesPcRip,
+    Category::GamesPs3,
+    Category::GamesXbox360,
+    Category::SoftwarePcIso,
+    Category::GamesPs4,
+    Category::Ebooks,
+];
+
 #[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 pub enum Category {
     #[serde(rename = "XXX (18+)")]
@@ -87,4 +119,156 @@ impl Category {
             Category::Ebooks => "35",
         }
     }
+
+    /// Return every category.
+    pub fn all() -> Vec<Category> {
+        ALL.to_vec()
+    }
+
+    /// Return the categories of a group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::category::Category;
+    /// use rarbg_api::category_group::CategoryGroup;
+    ///
+    /// assert_eq!(Category::all_in(CategoryGroup::Music), vec![Category::MusicMp3, Category::MusicFlac]);
+    /// ```
+    pub fn all_in(group: CategoryGroup) -> Vec<Category> {
+        ALL.iter()
+            .filter(|category| category.group() == group)
+            .copied()
+            .collect()
+    }
+
+    /// Return every movie category.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::api_parameters_builder::ApiParametersBuilder;
+    /// use rarbg_api::category::Category;
+    ///
+    /// let parameters = ApiParametersBuilder::new()
+    ///     .categories(Category::all_movies())
+    ///     .build();
+    /// assert_eq!(parameters.categories().unwrap().len(), 12);
+    /// ```
+    pub fn all_movies() -> Vec<Category> {
+        Category::all_in(CategoryGroup::Movies)
+    }
+
+    /// Return every TV category.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::category::Category;
+    ///
+    /// assert_eq!(
+    ///     Category::all_tv(),
+    ///     vec![Category::TvEpisodes, Category::TvHdEpisodes, Category::TvUhdEpisodes]
+    /// );
+    /// ```
+    pub fn all_tv() -> Vec<Category> {
+        Category::all_in(CategoryGroup::Tv)
+    }
+
+    /// Return the group that the category belongs to.
+    ///
+    /// `Category::TvUhdEpisodes` belongs to `CategoryGroup::Tv` although the API names it
+    /// "Movies/TV-UHD-episodes".
+    pub fn group(&self) -> CategoryGroup {
+        match self {
+            Category::Xxx => CategoryGroup::Adult,
+            Category::MoviesXvid
+            | Category::MoviesXvid720
+            | Category::MoviesX264
+            | Category::MoviesX2641080
+            | Category::MoviesX264720
+            | Category::MoviesX2643d
+            | Category::MoviesX2644k
+            | Category::MoviesX2651080
+            | Category::MoviesX2654k
+            | Category::MoviesX2654kHdr
+            | Category::MoviesFullBd
+            | Category::MoviesBdRemux => CategoryGroup::Movies,
+            Category::TvEpisodes | Category::TvHdEpisodes | Category::TvUhdEpisodes => {
+                CategoryGroup::Tv
+            }
+            Category::MusicMp3 | Category::MusicFlac => CategoryGroup::Music,
+            Category::GamesPcIso
+            | Category::GamesPcRip
+            | Category::GamesPs3
+            | Category::GamesXbox360
+            | Category::GamesPs4 => CategoryGroup::Games,
+            Category::SoftwarePcIso => CategoryGroup::Software,
+            Category::Ebooks => CategoryGroup::Books,
+        }
+    }
+
+    /// Return the resolution of the videos of the category.
+    ///
+    /// Return None if the category is not a video one or mixes resolutions, like
+    /// `Category::TvHdEpisodes` with 720p and 1080p.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::category::Category;
+    /// use rarbg_api::resolution::Resolution;
+    /// use rarbg_api::video_codec::VideoCodec;
+    ///
+    /// let category = Category::MoviesX2654kHdr;
+    /// assert_eq!(category.resolution(), Some(Resolution::UltraHd2160));
+    /// assert_eq!(category.video_codec(), Some(VideoCodec::H265));
+    /// assert!(category.is_hdr());
+    /// ```
+    pub fn resolution(&self) -> Option<Resolution> {
+        match self {
+            Category::MoviesXvid | Category::MoviesX264 | Category::TvEpisodes => {
+                Some(Resolution::Sd)
+            }
+            Category::MoviesXvid720 | Category::MoviesX264720 => Some(Resolution::Hd720),
+            Category::MoviesX2641080
+            | Category::MoviesX2643d
+            | Category::MoviesX2651080
+            | Category::MoviesFullBd
+            | Category::MoviesBdRemux => Some(Resolution::FullHd1080),
+            Category::MoviesX2644k
+            | Category::MoviesX2654k
+            | Category::MoviesX2654kHdr
+            | Category::TvUhdEpisodes => Some(Resolution::UltraHd2160),
+            _ => None,
+        }
+    }
+
+    /// Return the video codec of the category.
+    ///
+    /// Return None if the category is not a video one or mixes codecs.
+    pub fn video_codec(&self) -> Option<VideoCodec> {
+        match self {
+            Category::MoviesXvid | Category::MoviesXvid720 => Some(VideoCodec::Xvid),
+            Category::MoviesX264
+            | Category::MoviesX2641080
+            | Category::MoviesX264720
+            | Category::MoviesX2643d
+            | Category::MoviesX2644k => Some(VideoCodec::H264),
+            Category::MoviesX2651080 | Category::MoviesX2654k | Category::MoviesX2654kHdr => {
+                Some(VideoCodec::H265)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return true if the videos of the category are HDR, otherwise false.
+    pub fn is_hdr(&self) -> bool {
+        *self == Category::MoviesX2654kHdr
+    }
+
+    /// Return true if the videos of the category are 3D, otherwise false.
+    pub fn is_3d(&self) -> bool {
+        *self == Category::MoviesX2643d
+    }
 }
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of content of a category.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum CategoryGroup {
+    Movies,
+    Tv,
+    Music,
+    Games,
+    Software,
+    Books,
+    Adult,
+}
+
+impl CategoryGroup {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CategoryGroup::Movies => "Movies",
+            CategoryGroup::Tv => "TV",
+            CategoryGroup::Music => "Music",
+            CategoryGroup::Games => "Games",
+            CategoryGroup::Software => "Software",
+            CategoryGroup::Books => "Books",
+            CategoryGroup::Adult => "Adult",
+        }
+    }
+}
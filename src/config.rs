@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use crate::retry_policy::RetryPolicy;
+use crate::token_refresh_policy::TokenRefreshPolicy;
+use crate::{ENDPOINT, REQUEST_TIME_LIMIT, TOKEN_LIFETIME, USER_AGENT};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub(crate) base_url: String,
+    pub(crate) user_agent: String,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) total_timeout: Option<Duration>,
+    pub(crate) proxy: Option<String>,
+    pub(crate) request_delay: Duration,
+    pub(crate) token_lifetime: Duration,
+    pub(crate) token_refresh_policy: TokenRefreshPolicy,
+    pub(crate) no_results_as_empty: bool,
+    pub(crate) retry_policy: RetryPolicy,
+}
+
+impl Config {
+    /// Return the URL of the API.
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_str()
+    }
+
+    /// Return the user agent sent with each request.
+    pub fn user_agent(&self) -> &str {
+        self.user_agent.as_str()
+    }
+
+    /// Return the maximum time allowed to connect to the API.
+    pub fn connect_timeout(&self) -> Option<&Duration> {
+        self.connect_timeout.as_ref()
+    }
+
+    /// Return the maximum time allowed for a request, from connecting until the body of the
+    /// response has been read.
+    pub fn total_timeout(&self) -> Option<&Duration> {
+        self.total_timeout.as_ref()
+    }
+
+    /// Return the URL of the proxy used to reach the API.
+    pub fn proxy(&self) -> Option<&String> {
+        self.proxy.as_ref()
+    }
+
+    /// Return the minimum delay between two requests.
+    pub fn request_delay(&self) -> &Duration {
+        &self.request_delay
+    }
+
+    /// Return how long a token is considered valid.
+    pub fn token_lifetime(&self) -> &Duration {
+        &self.token_lifetime
+    }
+
+    /// Return what the client does when the API rejects its token.
+    pub fn token_refresh_policy(&self) -> &TokenRefreshPolicy {
+        &self.token_refresh_policy
+    }
+
+    /// Return true if a search or a list without any match returns an empty Torrents,
+    /// otherwise false, the "No results found" error is returned.
+    pub fn no_results_as_empty(&self) -> &bool {
+        &self.no_results_as_empty
+    }
+
+    /// Return how the requests that failed are sent again.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            base_url: ENDPOINT.to_string(),
+            user_agent: USER_AGENT.to_string(),
+            connect_timeout: None,
+            total_timeout: None,
+            proxy: None,
+            request_delay: Duration::from_secs(REQUEST_TIME_LIMIT),
+            token_lifetime: Duration::from_secs(TOKEN_LIFETIME),
+            token_refresh_policy: TokenRefreshPolicy::default(),
+            no_results_as_empty: true,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
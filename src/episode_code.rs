@@ -0,0 +1,164 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A season, an episode or a range of episodes of a TV show, like "S03E07".
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::episode_code::EpisodeCode;
+///
+/// let code: EpisodeCode = "s3e7".parse().unwrap();
+/// assert_eq!(code, EpisodeCode::new(3, Some(7)));
+/// assert_eq!(code.to_string(), "S03E07");
+///
+/// let pack: EpisodeCode = "S03".parse().unwrap();
+/// assert!(pack.is_season_pack());
+///
+/// let double: EpisodeCode = "S03E07E08".parse().unwrap();
+/// assert!(double.is_multi_episode());
+/// assert_eq!(double.to_string(), "S03E07-E08");
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct EpisodeCode {
+    season: u32,
+    episode: Option<u32>,
+    last_episode: Option<u32>,
+}
+
+impl EpisodeCode {
+    /// Create an EpisodeCode for a whole season if `episode` is None, otherwise for one episode.
+    pub fn new(season: u32, episode: Option<u32>) -> Self {
+        EpisodeCode {
+            season,
+            episode,
+            last_episode: None,
+        }
+    }
+
+    /// Create an EpisodeCode for the episodes `first` to `last` of a season.
+    pub fn range(season: u32, first: u32, last: u32) -> Self {
+        EpisodeCode {
+            season,
+            episode: Some(first),
+            last_episode: if last > first { Some(last) } else { None },
+        }
+    }
+
+    /// Return the season number.
+    pub fn season(&self) -> u32 {
+        self.season
+    }
+
+    /// Return the first episode number, or None for a whole season.
+    pub fn episode(&self) -> Option<u32> {
+        self.episode
+    }
+
+    /// Return the last episode number of a multi-episode release.
+    pub fn last_episode(&self) -> Option<u32> {
+        self.last_episode.or(self.episode)
+    }
+
+    /// Return true if it covers a whole season, otherwise false.
+    pub fn is_season_pack(&self) -> bool {
+        self.episode.is_none()
+    }
+
+    /// Return true if it covers several episodes but not a whole season, otherwise false.
+    pub fn is_multi_episode(&self) -> bool {
+        self.last_episode.is_some()
+    }
+
+    /// Return true if the given episode is covered, otherwise false.
+    ///
+    /// A season pack covers every episode of its season.
+    pub fn contains(&self, season: u32, episode: u32) -> bool {
+        match (self.episode, self.last_episode()) {
+            (Some(first), Some(last)) => {
+                self.season == season && first <= episode && episode <= last
+            }
+            _ => self.season == season,
+        }
+    }
+}
+
+impl FromStr for EpisodeCode {
+    type Err = String;
+
+    /// Parse codes like "S03E07", "S03", "S03E07E08E09", "S03E07-E08", "S03E07-08", "3x07" or
+    /// "3x07-08", whatever the case. Episodes must be given in increasing order.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("'{}' is not an episode code", value);
+        let lowercase = value.trim().to_ascii_lowercase();
+        let (season, rest) = if let Some(rest) = lowercase.strip_prefix('s') {
+            let (season, rest) = split_number(rest).ok_or_else(invalid)?;
+            if rest.is_empty() {
+                return Ok(EpisodeCode::new(season, None));
+            }
+            (season, rest.strip_prefix('e').ok_or_else(invalid)?)
+        } else {
+            let (season, rest) = split_number(lowercase.as_str()).ok_or_else(invalid)?;
+            // A resolution like "1920x1080" is not a season.
+            if lowercase.len() - rest.len() > 2 {
+                return Err(invalid());
+            }
+            (season, rest.strip_prefix('x').ok_or_else(invalid)?)
+        };
+        let (first, mut rest) = split_number(rest).ok_or_else(invalid)?;
+        let mut last = first;
+        while !rest.is_empty() {
+            let next = rest.strip_prefix('-').unwrap_or(rest);
+            let next = next.strip_prefix('e').unwrap_or(next);
+            match split_number(next) {
+                Some((episode, remaining)) if episode > last => {
+                    last = episode;
+                    rest = remaining;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(EpisodeCode::range(season, first, last))
+    }
+}
+
+impl TryFrom<String> for EpisodeCode {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<EpisodeCode> for String {
+    fn from(code: EpisodeCode) -> Self {
+        code.to_string()
+    }
+}
+
+impl fmt::Display for EpisodeCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "S{:02}", self.season)?;
+        if let Some(episode) = self.episode {
+            write!(f, "E{:02}", episode)?;
+        }
+        if let Some(last_episode) = self.last_episode {
+            write!(f, "-E{:02}", last_episode)?;
+        }
+        Ok(())
+    }
+}
+
+fn split_number(value: &str) -> Option<(u32, &str)> {
+    let end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    if end == 0 || end > 4 {
+        return None;
+    }
+    Some((value[..end].parse().ok()?, &value[end..]))
+}
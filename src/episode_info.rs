@@ -3,6 +3,12 @@ use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use crate::episode_code::EpisodeCode;
+use crate::imdb_id::ImdbId;
+use crate::tmdb_id::TmdbId;
+use crate::tv_rage_id::TvRageId;
+use crate::tvdb_id::TvdbId;
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct EpisodeInfo {
     imdb: Option<String>,
@@ -16,46 +22,49 @@ pub struct EpisodeInfo {
 }
 
 impl EpisodeInfo {
-    /// Return the TMDB id.
-    pub fn imdb_id(&self) -> Option<&String> {
-        self.imdb.as_ref()
+    /// Return the IMDB id, or None if it is missing or malformed.
+    pub fn imdb_id(&self) -> Option<ImdbId> {
+        self.imdb.as_ref().and_then(|id| id.parse().ok())
     }
 
-    /// Return the TVRage id.
-    pub fn tvrage_id(&self) -> Option<&String> {
-        self.tvrage.as_ref()
+    /// Return the TVRage id, or None if it is missing or malformed.
+    pub fn tvrage_id(&self) -> Option<TvRageId> {
+        self.tvrage.as_ref().and_then(|id| id.parse().ok())
     }
 
-    /// Return the TVDB id.
-    pub fn tvdb_id(&self) -> Option<&String> {
-        self.tvdb.as_ref()
+    /// Return the TVDB id, or None if it is missing or malformed.
+    pub fn tvdb_id(&self) -> Option<TvdbId> {
+        self.tvdb.as_ref().and_then(|id| id.parse().ok())
     }
 
-    /// Return the TMDB id.
-    pub fn tmdb_id(&self) -> Option<&String> {
-        self.themoviedb.as_ref()
+    /// Return the TMDB id, or None if it is missing or malformed.
+    pub fn tmdb_id(&self) -> Option<TmdbId> {
+        self.themoviedb.as_ref().and_then(|id| id.parse().ok())
     }
 
-    /// Return the airing date.
+    /// Return the airing date, or None if it is missing or malformed.
     pub fn air_date(&self) -> Option<NaiveDate> {
-        if self.airdate.is_some() {
-            let date = self.airdate.as_ref().unwrap();
-            if date == "0000-00-00" {
-                return None;
-            }
-            return Some(NaiveDate::from_str(date.as_str()).unwrap());
-        }
-        None
+        self.airdate
+            .as_ref()
+            .and_then(|date| NaiveDate::from_str(date.as_str()).ok())
+    }
+
+    /// Return the episode number, or None if it is missing or malformed.
+    pub fn episode_number(&self) -> Option<u32> {
+        self.epnum.as_ref().and_then(|number| number.parse().ok())
     }
 
-    /// Return the episode number.
-    pub fn episode_number(&self) -> Option<&String> {
-        self.epnum.as_ref()
+    /// Return the season number, or None if it is missing or malformed.
+    pub fn season_number(&self) -> Option<u32> {
+        self.seasonnum
+            .as_ref()
+            .and_then(|number| number.parse().ok())
     }
 
-    /// Return the season number.
-    pub fn season_number(&self) -> Option<&String> {
-        self.seasonnum.as_ref()
+    /// Return the season and episode numbers, or None if the season number is unknown.
+    pub fn episode_code(&self) -> Option<EpisodeCode> {
+        self.season_number()
+            .map(|season| EpisodeCode::new(season, self.episode_number()))
     }
 
     /// Return the title.
@@ -1,5 +1,10 @@
+use std::error;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::api_error_code::ApiErrorCode;
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Error {
     error: String,
@@ -16,4 +21,27 @@ impl Error {
     pub fn error_code(&self) -> &u8 {
         &self.error_code
     }
+
+    /// Return the error code given by the API as an ApiErrorCode.
+    pub fn code(&self) -> ApiErrorCode {
+        ApiErrorCode::from(self.error_code)
+    }
+
+    /// Return true if the search or the list did not match any torrent, otherwise false.
+    pub fn is_no_results(&self) -> bool {
+        self.code().is_no_results()
+    }
+
+    /// Return true if the same request may succeed later, otherwise false.
+    pub fn is_retryable(&self) -> bool {
+        self.code().is_retryable()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (error code {})", self.error, self.error_code)
+    }
 }
+
+impl error::Error for Error {}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Kinds of failure that a `RetryPolicy` can retry.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The request could not be sent or its response could not be read.
+    Transport,
+    /// The API refused the request because too many requests were made.
+    RateLimited,
+    /// The API answered with a 5xx status.
+    ServerError,
+    /// The API answered with an empty body.
+    EmptyResponse,
+    /// The API returned an error that may not happen if the request is sent again, like "Too
+    /// many requests per second".
+    Unavailable,
+}
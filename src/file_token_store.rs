@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::rarbg_error::RarBgError;
+use crate::token::Token;
+use crate::token_store::TokenStore;
+
+/// Keep the tokens in a JSON file, indexed by app_id.
+#[derive(Debug)]
+pub struct FileTokenStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileTokenStore {
+    /// Create a FileTokenStore that reads and writes the file at `path`.
+    ///
+    /// The file is created the first time a token is saved. It is replaced as a whole on every
+    /// save, through a temporary file next to it, and a file that is not valid JSON is replaced
+    /// by the saved token.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::file_token_store::FileTokenStore;
+    ///
+    /// let store = FileTokenStore::new("/tmp/rarbg_tokens.json");
+    /// ```
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        FileTokenStore {
+            path: path.as_ref().to_path_buf(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Return the path of the file.
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    fn read(&self) -> Result<String, RarBgError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(content),
+            Err(reason) if reason.kind() == ErrorKind::NotFound => Ok("{}".to_string()),
+            Err(reason) => Err(RarBgError::TokenStore(Box::new(reason))),
+        }
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self, app_id: &str) -> Result<Option<Token>, RarBgError> {
+        let _guard = self.lock.lock();
+        match serde_json::from_str::<HashMap<String, Token>>(self.read()?.as_str()) {
+            Ok(mut tokens) => Ok(tokens.remove(app_id)),
+            Err(reason) => Err(RarBgError::TokenStore(Box::new(reason))),
+        }
+    }
+
+    fn save(&self, app_id: &str, token: &Token) -> Result<(), RarBgError> {
+        let _guard = self.lock.lock();
+        /* A corrupt file is replaced, it cannot be read anyway. */
+        let mut tokens: HashMap<String, Token> =
+            serde_json::from_str(self.read()?.as_str()).unwrap_or_default();
+        tokens.insert(app_id.to_string(), token.clone());
+        let content = match serde_json::to_string_pretty(&tokens) {
+            Ok(content) => content,
+            Err(reason) => return Err(RarBgError::TokenStore(Box::new(reason))),
+        };
+        /* A file written in place is truncated if the process stops halfway. */
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        let result =
+            fs::write(&temporary, content).and_then(|_| fs::rename(&temporary, &self.path));
+        match result {
+            Ok(_) => Ok(()),
+            Err(reason) => {
+                let _ = fs::remove_file(&temporary);
+                Err(RarBgError::TokenStore(Box::new(reason)))
+            }
+        }
+    }
+}
@@ -1,17 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Default, Hash, PartialEq, Eq)]
 pub enum Format {
+    #[default]
     Json,
     JsonExtended,
 }
 
-impl Default for Format {
-    fn default() -> Self {
-        Format::Json
-    }
-}
-
 impl Format {
     pub fn as_str(&self) -> &str {
         match self {
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// A high dynamic range format of a video release.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Hdr {
+    Hdr10,
+    Hdr10Plus,
+    DolbyVision,
+    Hlg,
+}
+
+impl Hdr {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Hdr::Hdr10 => "HDR10",
+            Hdr::Hdr10Plus => "HDR10+",
+            Hdr::DolbyVision => "DV",
+            Hdr::Hlg => "HLG",
+        }
+    }
+
+    pub(crate) fn from_token(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "hdr" | "hdr10" => Some(Hdr::Hdr10),
+            "hdr10+" | "hdr10plus" => Some(Hdr::Hdr10Plus),
+            "dv" | "dovi" | "dolbyvision" => Some(Hdr::DolbyVision),
+            "hlg" => Some(Hdr::Hlg),
+            _ => None,
+        }
+    }
+}
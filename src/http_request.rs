@@ -0,0 +1,53 @@
+use reqwest::Method;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpRequest {
+    method: Method,
+    url: String,
+    query: Vec<(String, String)>,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// Create a request without query pairs nor headers.
+    pub fn new(method: Method, url: &str) -> Self {
+        HttpRequest {
+            method,
+            url: url.to_string(),
+            query: Vec::new(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Append a query pair to the request.
+    pub fn query(mut self, key: &str, value: &str) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Append a header to the request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Return the HTTP method.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// Return the URL without the query pairs.
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Return the query pairs in the order they were added.
+    pub fn query_pairs(&self) -> &Vec<(String, String)> {
+        &self.query
+    }
+
+    /// Return the headers in the order they were added.
+    pub fn headers(&self) -> &Vec<(String, String)> {
+        &self.headers
+    }
+}
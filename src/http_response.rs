@@ -0,0 +1,25 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+impl HttpResponse {
+    /// Create a response from a status code and a body.
+    pub fn new(status: u16, body: &str) -> Self {
+        HttpResponse {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    /// Return the HTTP status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Return the body.
+    pub fn body(&self) -> &str {
+        self.body.as_str()
+    }
+}
@@ -0,0 +1,73 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::parse_id_error::ParseIdError;
+
+/// An IMDB id, like "tt2861424".
+///
+/// It can be parsed from the id itself or from an imdb.com URL.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::imdb_id::ImdbId;
+///
+/// let id: ImdbId = "https://www.imdb.com/title/tt2861424/?ref_=nv_sr_1".parse().unwrap();
+/// assert_eq!(id.to_string(), "tt2861424");
+/// assert!("2861424".parse::<ImdbId>().is_err());
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ImdbId(String);
+
+impl ImdbId {
+    /// Return the id, like "tt2861424".
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl FromStr for ImdbId {
+    type Err = ParseIdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let id = if trimmed.contains("imdb.com/") {
+            trimmed
+                .split(['/', '?', '#'])
+                .find(|segment| segment.starts_with("tt"))
+                .unwrap_or("")
+        } else {
+            trimmed
+        };
+        match id.strip_prefix("tt") {
+            Some(digits) if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) => {
+                Ok(ImdbId(id.to_string()))
+            }
+            _ => Err(ParseIdError::new("IMDB", value)),
+        }
+    }
+}
+
+impl TryFrom<String> for ImdbId {
+    type Error = ParseIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ImdbId> for String {
+    fn from(id: ImdbId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for ImdbId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::http_request::HttpRequest;
+use crate::http_response::HttpResponse;
+
+/* The token changes at each run, and a cassette may be replayed by another app, neither is used
+to match a request. */
+const IGNORED_QUERY_KEYS: [&str; 2] = ["token", "app_id"];
+
+/// A request sent to the API and the response it got, as stored in a `Cassette`.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Interaction {
+    method: String,
+    url: String,
+    query: Vec<(String, String)>,
+    status: u16,
+    body: String,
+}
+
+impl Interaction {
+    /// Create an Interaction from a request and its response.
+    pub fn new(request: &HttpRequest, response: &HttpResponse) -> Self {
+        Interaction {
+            method: request.method().as_str().to_string(),
+            url: request.url().to_string(),
+            query: request.query_pairs().clone(),
+            status: response.status(),
+            body: response.body().to_string(),
+        }
+    }
+
+    /// Return the HTTP method of the request.
+    pub fn method(&self) -> &str {
+        self.method.as_str()
+    }
+
+    /// Return the URL of the request, without the query pairs.
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Return the query pairs of the request.
+    pub fn query_pairs(&self) -> &Vec<(String, String)> {
+        &self.query
+    }
+
+    /// Return the response recorded for the request.
+    pub fn response(&self) -> HttpResponse {
+        HttpResponse::new(self.status, self.body.as_str())
+    }
+
+    /// Return true if the request has the same method and query pairs, in any order and
+    /// whatever the token and the app_id, otherwise false.
+    pub fn matches(&self, request: &HttpRequest) -> bool {
+        self.method == request.method().as_str()
+            && matching_pairs(&self.query) == matching_pairs(request.query_pairs())
+    }
+}
+
+fn matching_pairs(query: &[(String, String)]) -> Vec<&(String, String)> {
+    let mut pairs: Vec<&(String, String)> = query
+        .iter()
+        .filter(|(key, _)| !IGNORED_QUERY_KEYS.contains(&key.as_str()))
+        .collect();
+    pairs.sort();
+    pairs
+}
@@ -4,41 +4,152 @@ extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
 
-use std::thread::sleep;
-use std::time::Duration;
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
 
-use reqwest::{Client, Error as ReqwestError, RequestBuilder, Response};
+use reqwest::{Method, StatusCode};
 use serde_json::Error as SerdeJsonError;
+use tokio::sync::Mutex;
 
+use crate::api_error_code::ApiErrorCode;
 use crate::api_parameters::ApiParameters;
+use crate::api_parameters_builder::ApiParametersBuilder;
+use crate::category::Category;
+use crate::config::Config;
 use crate::error::Error;
+use crate::format::Format;
+use crate::http_request::HttpRequest;
+use crate::http_response::HttpResponse;
+use crate::imdb_id::ImdbId;
+use crate::limit::Limit;
 use crate::mode::Mode;
+use crate::priority::Priority;
+use crate::rarbg_api_builder::RarBgApiBuilder;
+use crate::rarbg_error::RarBgError;
+use crate::rate_limiter::RateLimiter;
+use crate::request_queue::RequestQueue;
+use crate::search_id::SearchId;
+use crate::search_query::SearchQuery;
+use crate::tmdb_id::TmdbId;
 use crate::token::Token;
+use crate::token_refresh_policy::TokenRefreshPolicy;
+use crate::token_store::TokenStore;
 use crate::torrents::Torrents;
+use crate::transport::Transport;
+use crate::tvdb_id::TvdbId;
 
+pub mod api_error_code;
 pub mod api_parameters;
 pub mod api_parameters_builder;
+pub mod audio;
+pub mod blocking;
+pub mod cassette;
 pub mod category;
+pub mod category_group;
+pub mod config;
+pub mod episode_code;
 pub mod episode_info;
 pub mod error;
+pub mod error_class;
+pub mod file_token_store;
 pub mod format;
+pub mod hdr;
+pub mod http_request;
+pub mod http_response;
+pub mod imdb_id;
+pub mod interaction;
 pub mod limit;
+pub mod magnet;
+pub mod memory_token_store;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod mode;
+mod numeric_id;
+pub mod parse_id_error;
+pub mod parse_magnet_error;
+pub mod priority;
+pub mod quality_profile;
+pub mod quality_profile_builder;
+pub mod rarbg_api_builder;
+pub mod rarbg_error;
+pub mod rate_limiter;
+pub mod recording_transport;
+pub mod release;
+pub mod replaying_transport;
+pub mod request_queue;
+pub mod reqwest_transport;
+pub mod resolution;
+pub mod retry_policy;
+pub mod retry_policy_builder;
+pub mod score_breakdown;
+pub mod score_entry;
+pub mod search_id;
+pub mod search_query;
 pub mod sort_by;
+pub mod sort_key;
+pub mod sort_order;
+pub mod source;
+mod title_parser;
+pub mod title_regex;
+pub mod tmdb_id;
 pub mod token;
+pub mod token_refresh_policy;
+pub mod token_store;
 pub mod torrent;
+pub mod torrent_filter;
+pub mod torrent_sort;
 pub mod torrents;
+pub mod transport;
+pub mod tv_rage_id;
+pub mod tvdb_id;
+pub mod video_codec;
 
 /* The API has a 1req/2s limit. We take three extra seconds just to be sure. */
 const REQUEST_TIME_LIMIT: u64 = 5;
+/* Officially, a token is valid for 15 minutes but we keep it for 10 minutes. */
+const TOKEN_LIFETIME: u64 = 600;
 const USER_AGENT: &str =
     "Mozilla/5.0 (Macintosh; Intel Mac OS X 10.15; rv:73.0) Gecko/20100101 Firefox/73.0";
 const ENDPOINT: &str = "https://torrentapi.org/pubapi_v2.php";
 
-#[derive(Debug)]
+/// A RARBG client.
+///
+/// All its methods take `&self`, so it can be shared between tasks with an `Arc` or cloned:
+/// clones share the same token, rate limiter and transport.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use rarbg_api::RarBgApi;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let api = Arc::new(RarBgApi::new("RustExample").await.unwrap());
+///     let handles: Vec<_> = vec!["Rick and Morty", "Futurama"]
+///         .into_iter()
+///         .map(|name| {
+///             let api = api.clone();
+///             tokio::spawn(async move { api.search(name, None).await })
+///         })
+///         .collect();
+///     for handle in handles {
+///         let result = handle.await.unwrap();
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
 pub struct RarBgApi {
     app_id: String,
-    token: Token,
+    token: Arc<RwLock<Token>>,
+    token_refresh: Arc<Mutex<()>>,
+    transport: Arc<dyn Transport>,
+    rate_limiter: Arc<RateLimiter>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    request_queue: Arc<RequestQueue>,
+    priority: Priority,
+    config: Config,
 }
 
 impl RarBgApi {
@@ -51,7 +162,7 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     ///     let app_id = api.app_id();
     /// }
     /// ```
@@ -69,20 +180,101 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = RarBgApi::new("RustExample").await;
-    ///     let token: &Token = api.token();
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
+    ///     let token: Token = api.token();
     /// }
     /// ```
-    pub fn token(&self) -> &Token {
-        &self.token
+    pub fn token(&self) -> Token {
+        match self.token.read() {
+            Ok(token) => token.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Return the configuration of the client.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::RarBgApi;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
+    ///     let base_url = api.config().base_url();
+    /// }
+    /// ```
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Return the rate limiter used by the client.
+    ///
+    /// It can be given to `RarBgApiBuilder::rate_limiter` to share it with another client
+    /// using the same app_id.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::RarBgApi;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
+    ///     let interval = api.rate_limiter().interval();
+    /// }
+    /// ```
+    pub fn rate_limiter(&self) -> &Arc<RateLimiter> {
+        &self.rate_limiter
+    }
+
+    /// Return the priority of the requests sent by the client.
+    pub fn priority(&self) -> &Priority {
+        &self.priority
+    }
+
+    /// Return a client sharing the same token, rate limiter and request queue, whose requests
+    /// are sent with the given priority.
+    ///
+    /// When several tasks query at the same time, their requests are sent one at a time, the
+    /// ones with the highest priority first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::priority::Priority;
+    /// use rarbg_api::RarBgApi;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
+    ///     let background = api.with_priority(Priority::Background);
+    ///     let interactive = api.with_priority(Priority::Interactive);
+    ///     let (polled, searched) = tokio::join!(
+    ///         background.list(None),
+    ///         interactive.search("Rick and Morty", None)
+    ///     );
+    /// }
+    /// ```
+    pub fn with_priority(&self, priority: Priority) -> RarBgApi {
+        RarBgApi {
+            priority,
+            ..self.clone()
+        }
     }
 
-    /// Create a new RARBG client.
+    /// Create a new RARBG client with the default configuration.
+    ///
+    /// Use `RarBgApiBuilder` to change the configuration.
     ///
     /// # Arguments
     ///
     /// * `app_id` - A string slice that holds the name of your app.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if a token cannot be retrieve from the API.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -91,83 +283,316 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     /// }
     /// ```
-    pub async fn new(app_id: &str) -> Self {
-        RarBgApi {
-            token: Token::new(app_id).await,
+    pub async fn new(app_id: &str) -> Result<Self, RarBgError> {
+        RarBgApiBuilder::new().build(app_id).await
+    }
+
+    /// Create a new RARBG client that sends its requests through the given transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - A string slice that holds the name of your app.
+    /// * `transport` - The transport used to send requests to the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a token cannot be retrieve from the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use rarbg_api::RarBgApi;
+    /// use rarbg_api::reqwest_transport::ReqwestTransport;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let transport = Arc::new(ReqwestTransport::new());
+    ///     let api = RarBgApi::with_transport("RustExample", transport).await.unwrap();
+    /// }
+    /// ```
+    pub async fn with_transport(
+        app_id: &str,
+        transport: Arc<dyn Transport>,
+    ) -> Result<Self, RarBgError> {
+        RarBgApiBuilder::new()
+            .transport(transport)
+            .build(app_id)
+            .await
+    }
+
+    /// Create a new RARBG client that loads its token from the given store, and saves there
+    /// every new token retrieved from the API.
+    ///
+    /// A token is only retrieved from the API if the store has none, if it has expired or if the
+    /// store cannot be read. A token that cannot be saved is still used.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - A string slice that holds the name of your app.
+    /// * `token_store` - The store that keeps the tokens between two runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a token cannot be retrieve from the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use rarbg_api::RarBgApi;
+    /// use rarbg_api::file_token_store::FileTokenStore;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let store = Arc::new(FileTokenStore::new("/tmp/rarbg_tokens.json"));
+    ///     let api = RarBgApi::with_token_store("RustExample", store).await.unwrap();
+    /// }
+    /// ```
+    pub async fn with_token_store(
+        app_id: &str,
+        token_store: Arc<dyn TokenStore>,
+    ) -> Result<Self, RarBgError> {
+        RarBgApiBuilder::new()
+            .token_store(token_store)
+            .build(app_id)
+            .await
+    }
+
+    pub(crate) async fn from_config(
+        app_id: &str,
+        config: Config,
+        transport: Arc<dyn Transport>,
+        rate_limiter: Arc<RateLimiter>,
+        token_store: Option<Arc<dyn TokenStore>>,
+        request_queue: Arc<RequestQueue>,
+    ) -> Result<Self, RarBgError> {
+        let stored_token = match &token_store {
+            /* A store that cannot be read is only a cache miss, the new token replaces its content. */
+            Some(token_store) => match token_store.load(app_id) {
+                Ok(token) => token,
+                Err(reason) => {
+                    log::warn!("Failed to load the token of {}: {}", app_id, reason);
+                    None
+                }
+            },
+            None => None,
+        };
+        let token = match stored_token {
+            Some(token) if token.is_valid_for(config.token_lifetime) => token,
+            _ => {
+                rate_limiter.wait().await;
+                let token = Token::fetch(app_id, transport.as_ref(), &config).await?;
+                if let Some(token_store) = &token_store {
+                    save_token(token_store.as_ref(), app_id, &token);
+                }
+                token
+            }
+        };
+        Ok(RarBgApi {
             app_id: app_id.to_string(),
-        }
+            token: Arc::new(RwLock::new(token)),
+            token_refresh: Arc::new(Mutex::new(())),
+            transport,
+            rate_limiter,
+            token_store,
+            request_queue,
+            priority: Priority::default(),
+            config,
+        })
     }
 
     async fn request(
-        &mut self,
+        &self,
         search_value: Option<&[(&str, &str)]>,
         mode: Mode,
         parameters: Option<&ApiParameters>,
-    ) -> Result<Torrents, Error> {
-        if !self.token.is_valid() {
-            self.token = Token::new(self.app_id()).await;
+    ) -> Result<Torrents, RarBgError> {
+        let mut retries = 0;
+        let mut token_refreshed = false;
+        loop {
+            // Sending again with a refreshed token is not an attempt of the retry policy.
+            let attempts = retries + 1;
+            let permit = self.request_queue.acquire(self.priority).await;
+            let mut used_token = None;
+            let result = match self.valid_token().await {
+                Ok(token) => {
+                    let result = self.send(&token, search_value, mode, parameters).await;
+                    used_token = Some(token);
+                    result
+                }
+                Err(error) => Err(error),
+            };
+            let error = match result {
+                Ok(mut torrents) => {
+                    torrents.attempts = attempts;
+                    return Ok(torrents);
+                }
+                Err(error) => error,
+            };
+            match (error, used_token) {
+                (RarBgError::Api(error), Some(token))
+                    if !token_refreshed
+                        && self.config.token_refresh_policy == TokenRefreshPolicy::RetryOnce
+                        && error.code().is_invalid_token() =>
+                {
+                    self.refresh_token(&token).await?;
+                    token_refreshed = true;
+                }
+                (RarBgError::Api(error), _)
+                    if self.config.no_results_as_empty && error.is_no_results() =>
+                {
+                    return Ok(Torrents {
+                        attempts,
+                        ..Torrents::default()
+                    });
+                }
+                (error, _)
+                    if retries + 1 < self.config.retry_policy.max_attempts
+                        && self.config.retry_policy.is_retryable(&error) =>
+                {
+                    retries += 1;
+                    drop(permit);
+                    tokio::time::sleep(self.config.retry_policy.delay(retries)).await;
+                }
+                (error, _) if attempts > 1 => {
+                    return Err(RarBgError::Retried {
+                        attempts,
+                        last_error: Box::new(error),
+                    });
+                }
+                (error, _) => return Err(error),
+            }
         }
-        sleep(Duration::new(REQUEST_TIME_LIMIT, 0));
+    }
 
-        let client: Client = Client::builder().user_agent(USER_AGENT).build().unwrap();
+    async fn send(
+        &self,
+        token: &Token,
+        search_value: Option<&[(&str, &str)]>,
+        mode: Mode,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        self.rate_limiter.wait().await;
 
-        let mut request: RequestBuilder = client
-            .get(ENDPOINT)
-            .query(&[("mode", mode.as_str())])
-            .query(&[("token", self.token().value())])
-            .query(&[("app_id", self.app_id())]);
+        let request = self.prepare(token, search_value, mode, parameters);
+        let response = self.transport.send(&request).await?;
+        RarBgApi::parse(response)
+    }
 
-        if search_value.is_some() {
-            request = request.query(search_value.unwrap());
+    async fn valid_token(&self) -> Result<Token, RarBgError> {
+        let token = self.token();
+        if token.is_valid_for(self.config.token_lifetime) {
+            return Ok(token);
         }
+        self.refresh_token(&token).await?;
+        Ok(self.token())
+    }
+
+    /* Only one task retrieves a new token, the others wait for it and reuse it. */
+    async fn refresh_token(&self, stale_token: &Token) -> Result<(), RarBgError> {
+        let _refresh = self.token_refresh.lock().await;
+        if self.token() != *stale_token {
+            return Ok(());
+        }
+        self.rate_limiter.wait().await;
+        let token = Token::fetch(self.app_id(), self.transport.as_ref(), &self.config).await?;
+        match self.token.write() {
+            Ok(mut current) => *current = token.clone(),
+            Err(poisoned) => *poisoned.into_inner() = token.clone(),
+        }
+        if let Some(token_store) = &self.token_store {
+            save_token(token_store.as_ref(), self.app_id(), &token);
+        }
+        Ok(())
+    }
 
-        if parameters.is_some() {
-            let pm = parameters.unwrap();
+    fn prepare(
+        &self,
+        token: &Token,
+        search_value: Option<&[(&str, &str)]>,
+        mode: Mode,
+        parameters: Option<&ApiParameters>,
+    ) -> HttpRequest {
+        let mut request = HttpRequest::new(Method::GET, self.config.base_url())
+            .header("User-Agent", self.config.user_agent())
+            .query("mode", mode.as_str())
+            .query("token", token.value())
+            .query("app_id", self.app_id());
+
+        if let Some(search_value) = search_value {
+            for (key, value) in search_value {
+                request = request.query(key, value);
+            }
+        }
+
+        if let Some(pm) = parameters {
             request = request
-                .query(&[("ranked", *pm.ranked() as isize)])
-                .query(&[("sort", &pm.sort_by().as_str())])
-                .query(&[("limit", pm.limit().as_str())])
-                .query(&[("format", pm.format().as_str())]);
+                .query("ranked", if *pm.ranked() { "1" } else { "0" })
+                .query("sort", pm.sort_by().as_str())
+                .query("limit", pm.limit().as_str())
+                .query("format", pm.format().as_str());
 
-            if pm.minimum_seeders().is_some() {
-                request = request.query(&[("min_seeders", pm.minimum_seeders().unwrap())]);
+            if let Some(minimum_seeders) = pm.minimum_seeders() {
+                request = request.query("min_seeders", minimum_seeders.to_string().as_str());
             }
 
-            if pm.minimum_leechers().is_some() {
-                request = request.query(&[("min_leechers", pm.minimum_leechers().unwrap())]);
+            if let Some(minimum_leechers) = pm.minimum_leechers() {
+                request = request.query("min_leechers", minimum_leechers.to_string().as_str());
             }
 
-            if pm.categories().is_some() {
-                let categories = pm.categories().unwrap();
+            if let Some(categories) = pm.categories() {
                 let stringified_categories: Vec<&str> =
                     categories.iter().map(|c| c.as_str()).collect();
                 let joined_categories: String = stringified_categories.join(";");
-                request = request.query(&[("category", joined_categories)]);
+                request = request.query("category", joined_categories.as_str());
             }
         }
-        let response: Result<Response, ReqwestError> = request.send().await;
-
-        let content = match response {
-            Ok(res) => res.text().await,
-            Err(reason) => panic!("{}", reason),
-        };
+        request
+    }
 
-        let text = match content {
-            Ok(text) => text,
-            Err(reason) => panic!("{}", reason),
-        };
+    fn parse(response: HttpResponse) -> Result<Torrents, RarBgError> {
+        let status = StatusCode::from_u16(response.status());
+        match status {
+            Ok(StatusCode::TOO_MANY_REQUESTS) => return Err(RarBgError::RateLimited),
+            Ok(status) if status.is_success() => {}
+            _ => {
+                return Err(RarBgError::Http {
+                    status: response.status(),
+                    body: response.body().to_string(),
+                })
+            }
+        }
+        let text = response.body();
+        if text.trim().is_empty() {
+            return Err(RarBgError::EmptyResponse);
+        }
 
-        let torrents: Result<Torrents, SerdeJsonError> = serde_json::from_str(text.as_str());
+        let torrents: Result<Torrents, SerdeJsonError> = serde_json::from_str(text);
         match torrents {
-            Ok(torrents) => Ok(torrents),
-            Err(reason1) => {
-                let api_error: Result<Error, SerdeJsonError> = serde_json::from_str(text.as_str());
+            Ok(mut torrents) => {
+                let now = SystemTime::now();
+                for torrent in torrents.torrent_results.iter_mut() {
+                    torrent.fetched_at = Some(now);
+                }
+                Ok(torrents)
+            }
+            Err(source) => {
+                let api_error: Result<Error, SerdeJsonError> = serde_json::from_str(text);
                 match api_error {
-                    Ok(api_error) => Err(api_error),
-                    Err(reason2) => panic!("First reason: {}. Second reason: {}", reason1, reason2),
+                    Ok(api_error) if api_error.code() == ApiErrorCode::TooManyRequests => {
+                        Err(RarBgError::RateLimited)
+                    }
+                    Ok(api_error) => Err(RarBgError::Api(api_error)),
+                    Err(_) => Err(RarBgError::Decode {
+                        body: text.to_string(),
+                        source,
+                    }),
                 }
             }
         }
@@ -183,15 +608,53 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     ///     // It will get the 25 last ranked torrents
     ///     let result = api.list(None).await;
     /// }
     /// ```
-    pub async fn list(&mut self, parameters: Option<&ApiParameters>) -> Result<Torrents, Error> {
+    pub async fn list(&self, parameters: Option<&ApiParameters>) -> Result<Torrents, RarBgError> {
         self.request(None, Mode::List, parameters).await
     }
 
+    /// Search torrents matching a SearchQuery with some or no parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RarBgError::InvalidQuery` without sending anything if the query is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::RarBgApi;
+    /// use rarbg_api::search_id::SearchId;
+    /// use rarbg_api::search_query::SearchQuery;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
+    ///     // tt2861424 is Rick and Morty
+    ///     let query = SearchQuery::new()
+    ///         .with_id(SearchId::Imdb("tt2861424".parse().unwrap()))
+    ///         .with_string("S02E03");
+    ///     let result = api.query(&query, None).await;
+    /// }
+    /// ```
+    pub async fn query(
+        &self,
+        query: &SearchQuery,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        query.validate()?;
+        let pairs = query.query_pairs();
+        let search_value: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect();
+        self.request(Some(&search_value), Mode::Search, parameters)
+            .await
+    }
+
     /// Search torrents by its name with some or no parameters.
     ///
     /// # Example
@@ -202,16 +665,16 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     ///     let result = api.search("Rick and Morty", None).await;
     /// }
     /// ```
     pub async fn search(
-        &mut self,
+        &self,
         value: &str,
         parameters: Option<&ApiParameters>,
-    ) -> Result<Torrents, Error> {
-        self.request(Some(&[("search_string", value)]), Mode::Search, parameters)
+    ) -> Result<Torrents, RarBgError> {
+        self.query(&SearchQuery::new().with_string(value), parameters)
             .await
     }
 
@@ -225,17 +688,17 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     ///     // tt2861424 is Rick and Morty
-    ///     let result = api.search_by_imdb("tt2861424", None).await;
+    ///     let result = api.search_by_imdb(&"tt2861424".parse().unwrap(), None).await;
     /// }
     /// ```
     pub async fn search_by_imdb(
-        &mut self,
-        value: &str,
+        &self,
+        value: &ImdbId,
         parameters: Option<&ApiParameters>,
-    ) -> Result<Torrents, Error> {
-        self.request(Some(&[("search_imdb", value)]), Mode::Search, parameters)
+    ) -> Result<Torrents, RarBgError> {
+        self.query(&SearchQuery::new().with_id(value.clone()), parameters)
             .await
     }
 
@@ -249,17 +712,17 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     ///     // 275274 is Rick and Morty
-    ///     let result = api.search_by_tvdb("275274", None).await;
+    ///     let result = api.search_by_tvdb(&"275274".parse().unwrap(), None).await;
     /// }
     /// ```
     pub async fn search_by_tvdb(
-        &mut self,
-        value: &str,
+        &self,
+        value: &TvdbId,
         parameters: Option<&ApiParameters>,
-    ) -> Result<Torrents, Error> {
-        self.request(Some(&[("search_tvdb", value)]), Mode::Search, parameters)
+    ) -> Result<Torrents, RarBgError> {
+        self.query(&SearchQuery::new().with_id(*value), parameters)
             .await
     }
 
@@ -273,17 +736,78 @@ impl RarBgApi {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let mut api = RarBgApi::new("RustExample").await;
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
     ///     // 60625 is Rick and Morty
-    ///     let result = api.search_by_tmdb("60625", None).await;
+    ///     let result = api.search_by_tmdb(&"60625".parse().unwrap(), None).await;
     /// }
     /// ```
     pub async fn search_by_tmdb(
-        &mut self,
-        value: &str,
+        &self,
+        value: &TmdbId,
         parameters: Option<&ApiParameters>,
-    ) -> Result<Torrents, Error> {
-        self.request(Some(&[("search_tmdb", value)]), Mode::Search, parameters)
+    ) -> Result<Torrents, RarBgError> {
+        self.query(&SearchQuery::new().with_id(*value), parameters)
             .await
     }
+
+    /// Search the torrents of an episode, or of a whole season if `episode` is None, of a TV show.
+    ///
+    /// The show is searched by its id, then only the torrents of the given season and episode
+    /// are kept, season packs included. The episode code is read from the title, then from the
+    /// episode info, and torrents whose episode info names another show are dropped.
+    ///
+    /// The format is always `Format::JsonExtended`. Without parameters, the 100 last torrents of
+    /// the TV categories are searched, and without categories the TV categories are selected.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::RarBgApi;
+    /// use rarbg_api::search_id::SearchId;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = RarBgApi::new("RustExample").await.unwrap();
+    ///     // tt2861424 is Rick and Morty
+    ///     let show = SearchId::Imdb("tt2861424".parse().unwrap());
+    ///     let result = api.search_episode(&show, 3, Some(7), None).await;
+    /// }
+    /// ```
+    pub async fn search_episode(
+        &self,
+        show: &SearchId,
+        season: u32,
+        episode: Option<u32>,
+        parameters: Option<&ApiParameters>,
+    ) -> Result<Torrents, RarBgError> {
+        let mut parameters = match parameters {
+            Some(parameters) => parameters.clone(),
+            None => ApiParametersBuilder::new().limit(Limit::OneHundred).build(),
+        };
+        parameters.format = Format::JsonExtended;
+        if parameters.categories.is_none() {
+            parameters.categories = Some(Category::all_tv());
+        }
+        let mut torrents = self
+            .query(&SearchQuery::new().with_id(show.clone()), Some(&parameters))
+            .await?;
+        torrents.torrent_results.retain(|torrent| {
+            let same_show = torrent
+                .episode_info()
+                .is_none_or(|episode_info| show.matches(episode_info));
+            let same_episode = torrent.episode_code().is_some_and(|code| match episode {
+                Some(episode) => code.contains(season, episode),
+                None => code.season() == season,
+            });
+            same_show && same_episode
+        });
+        Ok(torrents)
+    }
+}
+
+/* The token is valid even if it could not be kept for the next run. */
+fn save_token(token_store: &dyn TokenStore, app_id: &str, token: &Token) {
+    if let Err(reason) = token_store.save(app_id, token) {
+        log::warn!("Failed to save the token of {}: {}", app_id, reason);
+    }
 }
@@ -1,18 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Default, Hash, PartialEq, Eq)]
 pub enum Limit {
+    #[default]
     TwentyFive,
     Fifty,
     OneHundred,
 }
 
-impl Default for Limit {
-    fn default() -> Self {
-        Limit::TwentyFive
-    }
-}
-
 impl Limit {
     pub fn as_str(&self) -> &str {
         match self {
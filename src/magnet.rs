@@ -0,0 +1,213 @@
+use std::fmt;
+use std::str::FromStr;
+
+use url::form_urlencoded;
+
+use crate::parse_magnet_error::ParseMagnetError;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// A BitTorrent magnet link, like the ones given by `Torrent::download`.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::magnet::Magnet;
+///
+/// let magnet: Magnet = "magnet:?xt=urn:btih:3f5e8d1e4c7a0b6f2d9e1c8b7a6f5e4d3c2b1a09\
+///     &dn=Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES\
+///     &tr=http%3A%2F%2Ftracker.trackerfix.com%3A80%2Fannounce&xl=1073741824"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(magnet.info_hash_hex(), "3f5e8d1e4c7a0b6f2d9e1c8b7a6f5e4d3c2b1a09");
+/// assert_eq!(magnet.info_hash_base32(), "H5PI2HSMPIFW6LM6DSFXU326JU6CWGQJ");
+/// assert_eq!(magnet.display_name().unwrap(), "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES");
+/// assert_eq!(magnet.trackers(), &vec!["http://tracker.trackerfix.com:80/announce".to_string()]);
+/// assert_eq!(magnet.size(), Some(&1073741824));
+///
+/// let same: Magnet = magnet.to_string().parse().unwrap();
+/// assert_eq!(same, magnet);
+///
+/// let base32: Magnet = "magnet:?xt=urn:btih:H5PI2HSMPIFW6LM6DSFXU326JU6CWGQJ".parse().unwrap();
+/// assert_eq!(base32.info_hash(), magnet.info_hash());
+/// assert!("magnet:?xt=urn:btih:3f5e8d".parse::<Magnet>().is_err());
+/// assert!("https://torrentapi.org".parse::<Magnet>().is_err());
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Magnet {
+    info_hash: [u8; 20],
+    display_name: Option<String>,
+    trackers: Vec<String>,
+    size: Option<u64>,
+}
+
+impl Magnet {
+    /// Create a magnet link for the given info hash, without display name, trackers nor size.
+    pub fn new(info_hash: [u8; 20]) -> Self {
+        Magnet {
+            info_hash,
+            display_name: None,
+            trackers: Vec::new(),
+            size: None,
+        }
+    }
+
+    /// Return the BitTorrent info hash (btih).
+    pub fn info_hash(&self) -> &[u8; 20] {
+        &self.info_hash
+    }
+
+    /// Return the info hash as 40 lowercase hexadecimal digits.
+    pub fn info_hash_hex(&self) -> String {
+        self.info_hash
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Return the info hash as 32 uppercase base32 characters.
+    pub fn info_hash_base32(&self) -> String {
+        let mut encoded = String::with_capacity(32);
+        let mut buffer: u64 = 0;
+        let mut bits = 0;
+        for byte in self.info_hash.iter() {
+            buffer = (buffer << 8) | u64::from(*byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                encoded.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+            }
+        }
+        encoded
+    }
+
+    /// Return the display name (dn).
+    pub fn display_name(&self) -> Option<&String> {
+        self.display_name.as_ref()
+    }
+
+    /// Return the tracker URLs (tr).
+    pub fn trackers(&self) -> &Vec<String> {
+        &self.trackers
+    }
+
+    /// Return the size in bytes (xl).
+    pub fn size(&self) -> Option<&u64> {
+        self.size.as_ref()
+    }
+
+    fn parse_info_hash(value: &str) -> Result<[u8; 20], ParseMagnetError> {
+        let encoded = match value.get(..9) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("urn:btih:") => &value[9..],
+            _ => return Err(ParseMagnetError::new("xt is not a btih urn")),
+        };
+        let mut info_hash = [0; 20];
+        match encoded.len() {
+            40 => {
+                /* Decoded from bytes, a multi-byte character cannot split a pair of digits. */
+                for (byte, pair) in info_hash.iter_mut().zip(encoded.as_bytes().chunks(2)) {
+                    match (hex_digit(pair[0]), hex_digit(pair[1])) {
+                        (Some(high), Some(low)) => *byte = high << 4 | low,
+                        _ => return Err(ParseMagnetError::new("the info hash is not hexadecimal")),
+                    }
+                }
+            }
+            32 => {
+                let mut buffer: u64 = 0;
+                let mut bits = 0;
+                let mut index = 0;
+                for c in encoded.bytes() {
+                    let value = BASE32_ALPHABET
+                        .iter()
+                        .position(|letter| *letter == c.to_ascii_uppercase())
+                        .ok_or_else(|| ParseMagnetError::new("the info hash is not base32"))?;
+                    buffer = (buffer << 5) | value as u64;
+                    bits += 5;
+                    if bits >= 8 {
+                        bits -= 8;
+                        info_hash[index] = (buffer >> bits) as u8;
+                        index += 1;
+                    }
+                }
+            }
+            _ => {
+                return Err(ParseMagnetError::new(
+                    "the info hash is neither 40 hexadecimal digits nor 32 base32 characters",
+                ))
+            }
+        }
+        Ok(info_hash)
+    }
+}
+
+impl FromStr for Magnet {
+    type Err = ParseMagnetError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let query = match value.trim().get(..8) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("magnet:?") => &value.trim()[8..],
+            _ => return Err(ParseMagnetError::new("it does not start with 'magnet:?'")),
+        };
+        let mut info_hash = None;
+        let mut display_name = None;
+        let mut trackers = Vec::new();
+        let mut size = None;
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                /* Hybrid magnet links also give a BitTorrent v2 hash in a btmh urn. */
+                "xt" if info_hash.is_none() && is_btih(value.as_ref()) => {
+                    info_hash = Some(Magnet::parse_info_hash(value.as_ref())?)
+                }
+                "dn" => display_name = Some(value.into_owned()),
+                "tr" => trackers.push(value.into_owned()),
+                "xl" => {
+                    size = Some(
+                        value
+                            .parse()
+                            .map_err(|_| ParseMagnetError::new("xl is not a number of bytes"))?,
+                    )
+                }
+                _ => {}
+            }
+        }
+        match info_hash {
+            Some(info_hash) => Ok(Magnet {
+                info_hash,
+                display_name,
+                trackers,
+                size,
+            }),
+            None => Err(ParseMagnetError::new("no xt is a btih urn")),
+        }
+    }
+}
+
+impl fmt::Display for Magnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        if let Some(display_name) = &self.display_name {
+            serializer.append_pair("dn", display_name);
+        }
+        for tracker in self.trackers.iter() {
+            serializer.append_pair("tr", tracker);
+        }
+        if let Some(size) = self.size {
+            serializer.append_pair("xl", size.to_string().as_str());
+        }
+        let parameters = serializer.finish();
+        write!(f, "magnet:?xt=urn:btih:{}", self.info_hash_hex())?;
+        if !parameters.is_empty() {
+            write!(f, "&{}", parameters)?;
+        }
+        Ok(())
+    }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|digit| digit as u8)
+}
+
+fn is_btih(xt: &str) -> bool {
+    xt.get(..9)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("urn:btih:"))
+}
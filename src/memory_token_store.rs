@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::rarbg_error::RarBgError;
+use crate::token::Token;
+use crate::token_store::TokenStore;
+
+/// Keep the tokens in memory, for the lifetime of the process.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    tokens: Mutex<HashMap<String, Token>>,
+}
+
+impl MemoryTokenStore {
+    /// Create an empty MemoryTokenStore.
+    pub fn new() -> Self {
+        MemoryTokenStore::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self, app_id: &str) -> Result<Option<Token>, RarBgError> {
+        let tokens = match self.tokens.lock() {
+            Ok(tokens) => tokens,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        Ok(tokens.get(app_id).cloned())
+    }
+
+    fn save(&self, app_id: &str, token: &Token) -> Result<(), RarBgError> {
+        let mut tokens = match self.tokens.lock() {
+            Ok(tokens) => tokens,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        tokens.insert(app_id.to_string(), token.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+//! A local server emulating torrentapi, to test your app without network access.
+//!
+//! Only available with the `mock` feature.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::api_error_code::ApiErrorCode;
+
+/// A response that the mock server gives to the next `mode=list` or `mode=search` request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockResponse {
+    /// Answer with these torrents, each one being a JSON object like `sample_torrent` returns.
+    Torrents(Vec<Value>),
+    /// Answer with an error given by the API.
+    Error(ApiErrorCode, String),
+    /// Answer with the "No results found" error.
+    NoResults,
+    /// Answer with the "Too many requests per second" error.
+    TooManyRequests,
+    /// Answer with the "Invalid token" error, whatever the token sent.
+    TokenExpired,
+    /// Answer with a raw HTTP status and body.
+    Status(u16, String),
+}
+
+/// A request received by the mock server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockRequest {
+    query: Vec<(String, String)>,
+}
+
+/// A local HTTP server emulating `get_token`, `mode=list` and `mode=search`.
+///
+/// Scripted responses are given in the order they were pushed, then the default torrents are
+/// given to every request. The server stops when it is dropped.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use rarbg_api::mock::{sample_torrent, MockResponse, MockServer};
+/// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let server = MockServer::start().await;
+///     server.push(MockResponse::Torrents(vec![sample_torrent("Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES")]));
+///
+///     let api = RarBgApiBuilder::new()
+///         .base_url(server.url().as_str())
+///         .request_delay(Duration::from_millis(1))
+///         .build("RustExample")
+///         .await
+///         .unwrap();
+///     let result = api.search("Rick and Morty", None).await.unwrap();
+///     assert_eq!(result.torrents().len(), 1);
+///     assert_eq!(server.requests().len(), 2);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    handle: JoinHandle<()>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    issued_tokens: u32,
+    valid_tokens: HashSet<String>,
+    responses: VecDeque<MockResponse>,
+    torrents: Vec<Value>,
+    requests: Vec<MockRequest>,
+}
+
+impl MockRequest {
+    /// Return the query pairs in the order they were sent.
+    pub fn query_pairs(&self) -> &Vec<(String, String)> {
+        &self.query
+    }
+
+    /// Return the value of the first query pair with the given key.
+    pub fn query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl MockServer {
+    /// Start a server listening on a random local port.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no local port is available.
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind the mock server.");
+        let address = listener
+            .local_addr()
+            .expect("Failed to get the address of the mock server.");
+        let state = Arc::new(Mutex::new(State::default()));
+        let accepted = state.clone();
+        let handle = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = accepted.clone();
+                tokio::spawn(async move {
+                    let _ = MockServer::serve(stream, state).await;
+                });
+            }
+        });
+        MockServer {
+            address,
+            state,
+            handle,
+        }
+    }
+
+    /// Return the URL to give to `RarBgApiBuilder::base_url`.
+    pub fn url(&self) -> String {
+        format!("http://{}/pubapi_v2.php", self.address)
+    }
+
+    /// Queue a response for the next `mode=list` or `mode=search` request.
+    pub fn push(&self, response: MockResponse) {
+        lock(&self.state).responses.push_back(response);
+    }
+
+    /// Set the torrents given when no scripted response is left.
+    ///
+    /// Without torrents, the "No results found" error is given.
+    pub fn set_torrents(&self, torrents: Vec<Value>) {
+        lock(&self.state).torrents = torrents;
+    }
+
+    /// Make every token issued so far expired.
+    pub fn expire_tokens(&self) {
+        lock(&self.state).valid_tokens.clear();
+    }
+
+    /// Return the number of tokens issued.
+    pub fn issued_tokens(&self) -> u32 {
+        lock(&self.state).issued_tokens
+    }
+
+    /// Return the requests received, `get_token` included.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        lock(&self.state).requests.clone()
+    }
+
+    async fn serve(mut stream: TcpStream, state: Arc<Mutex<State>>) -> std::io::Result<()> {
+        let mut head = Vec::new();
+        let mut buffer = [0; 1024];
+        while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                return Ok(());
+            }
+            head.extend_from_slice(&buffer[..read]);
+        }
+        let head = String::from_utf8_lossy(&head);
+        let target = head.split_whitespace().nth(1).unwrap_or("/");
+        let query = match target.split_once('?') {
+            Some((_, query)) => url::form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect(),
+            None => Vec::new(),
+        };
+        let (status, body) = MockServer::answer(&mut lock(&state), MockRequest { query });
+        let response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            if status < 400 { "OK" } else { "Error" },
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+
+    fn answer(state: &mut State, request: MockRequest) -> (u16, String) {
+        state.requests.push(request.clone());
+        if request.query("get_token").is_some() {
+            state.issued_tokens += 1;
+            let token = format!("mock-token-{}", state.issued_tokens);
+            state.valid_tokens.insert(token.clone());
+            return (200, json!({ "token": token }).to_string());
+        }
+        match request.query("token") {
+            None => return error(ApiErrorCode::NoTokenSet, "No token set!"),
+            Some(token) if !state.valid_tokens.contains(token) => {
+                return error(
+                    ApiErrorCode::TokenExpired,
+                    "Invalid token. Use get_token for a new one!",
+                )
+            }
+            Some(_) => {}
+        }
+        match state.responses.pop_front() {
+            Some(MockResponse::Torrents(torrents)) => torrent_results(torrents),
+            Some(MockResponse::Error(code, message)) => error(code, message.as_str()),
+            Some(MockResponse::NoResults) => error(ApiErrorCode::NoResults, "No results found"),
+            Some(MockResponse::TooManyRequests) => error(
+                ApiErrorCode::TooManyRequests,
+                "Too many requests per second. Maximum requests allowed are 1req/2sec Please try again later!",
+            ),
+            Some(MockResponse::TokenExpired) => error(
+                ApiErrorCode::TokenExpired,
+                "Invalid token. Use get_token for a new one!",
+            ),
+            Some(MockResponse::Status(status, body)) => (status, body),
+            None if state.torrents.is_empty() => {
+                error(ApiErrorCode::NoResults, "No results found")
+            }
+            None => torrent_results(state.torrents.clone()),
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Return a torrent as given by the API with the `json_extended` format.
+///
+/// Its info hash is derived from the title, so two torrents with the same title are the same
+/// release.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::mock::sample_torrent;
+///
+/// let torrent = sample_torrent("Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES");
+/// assert_eq!(torrent["title"], "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES");
+/// ```
+pub fn sample_torrent(title: &str) -> Value {
+    let mut hasher = DefaultHasher::new();
+    title.hash(&mut hasher);
+    let high = hasher.finish();
+    high.hash(&mut hasher);
+    let info_hash = (u128::from(high) << 64) | u128::from(hasher.finish());
+    json!({
+        "title": title,
+        "category": "TV HD Episodes",
+        "download": format!(
+            "magnet:?xt=urn:btih:{:040x}&dn={}&tr=http%3A%2F%2Ftracker.trackerfix.com%3A80%2Fannounce",
+            info_hash,
+            title
+        ),
+        "seeders": 42,
+        "leechers": 7,
+        "size": 1_073_741_824u64,
+        "pubdate": "2022-09-05 03:12:40 +0000",
+        "episode_info": {
+            "imdb": "tt2861424",
+            "tvrage": "33381",
+            "tvdb": "275274",
+            "themoviedb": "60625",
+            "airdate": "2022-09-04",
+            "epnum": "1",
+            "seasonnum": "6",
+            "title": "Solaricks"
+        },
+        "ranked": 1,
+        "info_page": "https://torrentapi.org/redirect_to_info.php?token=mock&p=1_1_1_1"
+    })
+}
+
+fn torrent_results(torrents: Vec<Value>) -> (u16, String) {
+    (200, json!({ "torrent_results": torrents }).to_string())
+}
+
+fn error(code: ApiErrorCode, message: &str) -> (u16, String) {
+    (
+        200,
+        json!({ "error": message, "error_code": code.as_u8() }).to_string(),
+    )
+}
+
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    match state.lock() {
+        Ok(state) => state,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
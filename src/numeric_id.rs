@@ -0,0 +1,73 @@
+//! Generates the ids made only of digits, like TVDB, TMDB and TVRage ids.
+
+/// Define a newtype over a u32 id, parsed from and serialized as a string of digits.
+///
+/// `$kind` names the database in the errors, like "TVDB".
+macro_rules! numeric_id {
+    ($(#[$attribute:meta])* $name:ident, $kind:literal) => {
+        $(#[$attribute])*
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            Hash,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            serde::Serialize,
+            serde::Deserialize,
+        )]
+        #[serde(try_from = "String", into = "String")]
+        pub struct $name(u32);
+
+        impl $name {
+            #[doc = concat!("Create a ", $kind, " id.")]
+            pub fn new(value: u32) -> Self {
+                $name(value)
+            }
+
+            /// Return the id.
+            pub fn value(&self) -> u32 {
+                self.0
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::parse_id_error::ParseIdError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                let trimmed = value.trim();
+                if trimmed.is_empty() || !trimmed.chars().all(|c| c.is_ascii_digit()) {
+                    return Err($crate::parse_id_error::ParseIdError::new($kind, value));
+                }
+                match trimmed.parse() {
+                    Ok(id) => Ok($name(id)),
+                    Err(_) => Err($crate::parse_id_error::ParseIdError::new($kind, value)),
+                }
+            }
+        }
+
+        impl std::convert::TryFrom<String> for $name {
+            type Error = $crate::parse_id_error::ParseIdError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.to_string()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+pub(crate) use numeric_id;
@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when a string is not a valid IMDB, TVDB, TMDB or TVRage id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseIdError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseIdError {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        ParseIdError {
+            kind,
+            value: value.to_string(),
+        }
+    }
+
+    /// Return the kind of id that was expected, like "IMDB".
+    pub fn kind(&self) -> &str {
+        self.kind
+    }
+
+    /// Return the string that failed to parse.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a valid {} id", self.value, self.kind)
+    }
+}
+
+impl Error for ParseIdError {}
@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when a magnet link is malformed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseMagnetError {
+    reason: String,
+}
+
+impl ParseMagnetError {
+    pub(crate) fn new(reason: &str) -> Self {
+        ParseMagnetError {
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Return why the magnet link is malformed.
+    pub fn reason(&self) -> &str {
+        self.reason.as_str()
+    }
+}
+
+impl fmt::Display for ParseMagnetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid magnet link: {}", self.reason)
+    }
+}
+
+impl Error for ParseMagnetError {}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// Order in which queued requests are sent, from the lowest to the highest.
+#[derive(
+    Copy, Clone, Serialize, Deserialize, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord,
+)]
+pub enum Priority {
+    /// Sent when no other request is waiting, like background polling.
+    Background,
+    /// Sent after the interactive requests and before the background ones, used by default.
+    #[default]
+    Normal,
+    /// Sent before any other request, like a search typed by a user.
+    Interactive,
+}
@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+use crate::category::Category;
+use crate::resolution::Resolution;
+use crate::score_breakdown::ScoreBreakdown;
+use crate::source::Source;
+use crate::title_parser;
+use crate::torrent::Torrent;
+use crate::video_codec::VideoCodec;
+
+/// What makes a release better than another, to choose among torrents.
+///
+/// A torrent gets the points of its category, of the resolution, source and video codec parsed
+/// from its title, of the preferred words found in its title, and `seeders_weight` points per
+/// power of ten of seeders. It is rejected if its size or its number of seeders is out of bounds
+/// or if its title contains a forbidden word. Words are matched as whole words of the title,
+/// whatever the case, so "CAM" is not found in "Scam".
+///
+/// It can be stored in configuration files since it is serializable.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QualityProfile {
+    pub(crate) categories: Vec<(Category, i32)>,
+    pub(crate) resolutions: Vec<(Resolution, i32)>,
+    pub(crate) sources: Vec<(Source, i32)>,
+    pub(crate) video_codecs: Vec<(VideoCodec, i32)>,
+    pub(crate) min_size: Option<u128>,
+    pub(crate) max_size: Option<u128>,
+    pub(crate) min_seeders: Option<u32>,
+    pub(crate) seeders_weight: f64,
+    pub(crate) preferred_words: Vec<(String, i32)>,
+    pub(crate) forbidden_words: Vec<String>,
+}
+
+impl QualityProfile {
+    /// Return the points given per category.
+    pub fn categories(&self) -> &Vec<(Category, i32)> {
+        &self.categories
+    }
+
+    /// Return the points given per resolution.
+    pub fn resolutions(&self) -> &Vec<(Resolution, i32)> {
+        &self.resolutions
+    }
+
+    /// Return the points given per source.
+    pub fn sources(&self) -> &Vec<(Source, i32)> {
+        &self.sources
+    }
+
+    /// Return the points given per video codec.
+    pub fn video_codecs(&self) -> &Vec<(VideoCodec, i32)> {
+        &self.video_codecs
+    }
+
+    /// Return the smallest size in bytes accepted.
+    pub fn min_size(&self) -> Option<&u128> {
+        self.min_size.as_ref()
+    }
+
+    /// Return the biggest size in bytes accepted.
+    pub fn max_size(&self) -> Option<&u128> {
+        self.max_size.as_ref()
+    }
+
+    /// Return the smallest number of seeders accepted.
+    pub fn min_seeders(&self) -> Option<&u32> {
+        self.min_seeders.as_ref()
+    }
+
+    /// Return the points given per power of ten of seeders.
+    pub fn seeders_weight(&self) -> &f64 {
+        &self.seeders_weight
+    }
+
+    /// Return the points given per word found in the title.
+    pub fn preferred_words(&self) -> &Vec<(String, i32)> {
+        &self.preferred_words
+    }
+
+    /// Return the words that reject a torrent when found in its title.
+    pub fn forbidden_words(&self) -> &Vec<String> {
+        &self.forbidden_words
+    }
+
+    /// Score a torrent, explaining where the points come from.
+    pub fn score(&self, torrent: &Torrent) -> ScoreBreakdown {
+        let mut breakdown = ScoreBreakdown::default();
+        if let Some((category, points)) = find(&self.categories, torrent.category()) {
+            breakdown.add(format!("category {:?}", category), points);
+        }
+
+        if let Some(release) = torrent.release_info() {
+            if let Some((resolution, points)) = release
+                .resolution()
+                .and_then(|r| find(&self.resolutions, r))
+            {
+                breakdown.add(format!("resolution {}", resolution.as_str()), points);
+            }
+            if let Some((source, points)) = release.source().and_then(|s| find(&self.sources, s)) {
+                breakdown.add(format!("source {}", source.as_str()), points);
+            }
+            if let Some((codec, points)) = release
+                .video_codec()
+                .and_then(|c| find(&self.video_codecs, c))
+            {
+                breakdown.add(format!("video codec {}", codec.as_str()), points);
+            }
+        }
+
+        if let Some(size) = torrent.size() {
+            if self.min_size.is_some_and(|min| *size < min) {
+                breakdown.reject(format!("size {} is below the minimum", size));
+            }
+            if self.max_size.is_some_and(|max| *size > max) {
+                breakdown.reject(format!("size {} is above the maximum", size));
+            }
+        }
+
+        if let Some(seeders) = torrent.seeders() {
+            if self.min_seeders.is_some_and(|min| *seeders < min) {
+                breakdown.reject(format!("{} seeders is below the minimum", seeders));
+            }
+            let points = (self.seeders_weight * (f64::from(*seeders) + 1.0).log10()).round();
+            breakdown.add(format!("{} seeders", seeders), points as i32);
+        }
+
+        if let Some(title) = torrent.title().or_else(|| torrent.filename()) {
+            for (word, points) in self.preferred_words.iter() {
+                if title_parser::contains_words(title, word) {
+                    breakdown.add(format!("preferred word \"{}\"", word), *points);
+                }
+            }
+            for word in self.forbidden_words.iter() {
+                if title_parser::contains_words(title, word) {
+                    breakdown.reject(format!("forbidden word \"{}\"", word));
+                }
+            }
+        }
+        breakdown
+    }
+}
+
+impl Default for QualityProfile {
+    fn default() -> Self {
+        QualityProfile {
+            categories: Vec::new(),
+            resolutions: Vec::new(),
+            sources: Vec::new(),
+            video_codecs: Vec::new(),
+            min_size: None,
+            max_size: None,
+            min_seeders: None,
+            seeders_weight: 10.0,
+            preferred_words: Vec::new(),
+            forbidden_words: Vec::new(),
+        }
+    }
+}
+
+fn find<T: PartialEq + Copy>(scores: &[(T, i32)], value: &T) -> Option<(T, i32)> {
+    scores.iter().find(|(key, _)| key == value).copied()
+}
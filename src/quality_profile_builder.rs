@@ -0,0 +1,209 @@
+use crate::category::Category;
+use crate::quality_profile::QualityProfile;
+use crate::resolution::Resolution;
+use crate::source::Source;
+use crate::video_codec::VideoCodec;
+
+#[derive(Clone, Debug)]
+pub struct QualityProfileBuilder {
+    profile: QualityProfile,
+}
+
+impl QualityProfileBuilder {
+    /// You can give points to the torrents of a category, negative points to avoid it.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::category::Category;
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().category(Category::TvHdEpisodes, 10);
+    /// ```
+    pub fn category(&mut self, category: Category, points: i32) -> &mut QualityProfileBuilder {
+        set(&mut self.profile.categories, category, points);
+        self
+    }
+
+    /// You can give points to the releases of a resolution.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    /// use rarbg_api::resolution::Resolution;
+    ///
+    /// let qpb = QualityProfileBuilder::new().resolution(Resolution::FullHd1080, 100);
+    /// ```
+    pub fn resolution(
+        &mut self,
+        resolution: Resolution,
+        points: i32,
+    ) -> &mut QualityProfileBuilder {
+        set(&mut self.profile.resolutions, resolution, points);
+        self
+    }
+
+    /// You can give points to the releases ripped from a source.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    /// use rarbg_api::source::Source;
+    ///
+    /// let qpb = QualityProfileBuilder::new().source(Source::BluRay, 50);
+    /// ```
+    pub fn source(&mut self, source: Source, points: i32) -> &mut QualityProfileBuilder {
+        set(&mut self.profile.sources, source, points);
+        self
+    }
+
+    /// You can give points to the releases encoded with a video codec.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    /// use rarbg_api::video_codec::VideoCodec;
+    ///
+    /// let qpb = QualityProfileBuilder::new().video_codec(VideoCodec::H265, 20);
+    /// ```
+    pub fn video_codec(
+        &mut self,
+        video_codec: VideoCodec,
+        points: i32,
+    ) -> &mut QualityProfileBuilder {
+        set(&mut self.profile.video_codecs, video_codec, points);
+        self
+    }
+
+    /// You can reject the torrents smaller than a size in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().min_size(200 * 1024 * 1024);
+    /// ```
+    pub fn min_size(&mut self, min_size: u128) -> &mut QualityProfileBuilder {
+        self.profile.min_size = Some(min_size);
+        self
+    }
+
+    /// You can reject the torrents bigger than a size in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().max_size(4 * 1024 * 1024 * 1024);
+    /// ```
+    pub fn max_size(&mut self, max_size: u128) -> &mut QualityProfileBuilder {
+        self.profile.max_size = Some(max_size);
+        self
+    }
+
+    /// You can reject the torrents with fewer seeders.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().min_seeders(5);
+    /// ```
+    pub fn min_seeders(&mut self, min_seeders: u32) -> &mut QualityProfileBuilder {
+        self.profile.min_seeders = Some(min_seeders);
+        self
+    }
+
+    /// You can choose how many points are given per power of ten of seeders.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().seeders_weight(25.0);
+    /// ```
+    pub fn seeders_weight(&mut self, seeders_weight: f64) -> &mut QualityProfileBuilder {
+        self.profile.seeders_weight = seeders_weight;
+        self
+    }
+
+    /// You can give points to the torrents whose title contains a word, or several words in a
+    /// row.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().preferred_word("PROPER", 5);
+    /// ```
+    pub fn preferred_word(&mut self, word: &str, points: i32) -> &mut QualityProfileBuilder {
+        set(&mut self.profile.preferred_words, word.to_string(), points);
+        self
+    }
+
+    /// You can reject the torrents whose title contains a word, or several words in a row.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    ///
+    /// let qpb = QualityProfileBuilder::new().forbidden_word("HDCAM");
+    /// ```
+    pub fn forbidden_word(&mut self, word: &str) -> &mut QualityProfileBuilder {
+        if !self.profile.forbidden_words.iter().any(|w| w == word) {
+            self.profile.forbidden_words.push(word.to_string());
+        }
+        self
+    }
+
+    /// Create a new QualityProfileBuilder.
+    ///
+    /// Default values:
+    /// * No points for categories, resolutions, sources, video codecs nor words
+    /// * No size nor seeders bounds
+    /// * 10 points per power of ten of seeders
+    pub fn new() -> Self {
+        QualityProfileBuilder {
+            profile: QualityProfile::default(),
+        }
+    }
+
+    /// Construct an immutable QualityProfile that can be use with Torrents.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::quality_profile::QualityProfile;
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    /// use rarbg_api::resolution::Resolution;
+    /// use rarbg_api::source::Source;
+    ///
+    /// let qp: QualityProfile = QualityProfileBuilder::new()
+    ///                           .resolution(Resolution::FullHd1080, 100)
+    ///                           .resolution(Resolution::UltraHd2160, 50)
+    ///                           .source(Source::BluRay, 30)
+    ///                           .max_size(8 * 1024 * 1024 * 1024)
+    ///                           .min_seeders(3)
+    ///                           .forbidden_word("CAM")
+    ///                           .build();
+    /// assert_eq!(*qp.resolutions(), vec![(Resolution::FullHd1080, 100), (Resolution::UltraHd2160, 50)]);
+    /// assert_eq!(*qp.max_size().unwrap(), 8 * 1024 * 1024 * 1024);
+    /// assert_eq!(*qp.min_seeders().unwrap(), 3);
+    /// assert_eq!(*qp.seeders_weight(), 10.0);
+    /// ```
+    pub fn build(&self) -> QualityProfile {
+        self.profile.clone()
+    }
+}
+
+impl Default for QualityProfileBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Set the points of a key, replacing the ones given before.
+fn set<T: PartialEq>(scores: &mut Vec<(T, i32)>, key: T, points: i32) {
+    match scores.iter_mut().find(|(k, _)| *k == key) {
+        Some(score) => score.1 = points,
+        None => scores.push((key, points)),
+    }
+}
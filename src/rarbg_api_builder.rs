@@ -0,0 +1,377 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Client, Proxy};
+
+use crate::config::Config;
+use crate::priority::Priority;
+use crate::rarbg_error::RarBgError;
+use crate::rate_limiter::RateLimiter;
+use crate::request_queue::RequestQueue;
+use crate::reqwest_transport::ReqwestTransport;
+use crate::retry_policy::RetryPolicy;
+use crate::token_refresh_policy::TokenRefreshPolicy;
+use crate::token_store::TokenStore;
+use crate::transport::Transport;
+use crate::RarBgApi;
+
+#[derive(Clone, Debug)]
+pub struct RarBgApiBuilder {
+    config: Config,
+    transport: Option<Arc<dyn Transport>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    request_queue: Option<Arc<RequestQueue>>,
+    priority: Priority,
+}
+
+impl RarBgApiBuilder {
+    /// You can point the client to a mirror or to a local server.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().base_url("http://localhost:8080/pubapi_v2.php");
+    /// ```
+    pub fn base_url(&mut self, base_url: &str) -> &mut RarBgApiBuilder {
+        self.config.base_url = base_url.to_string();
+        self
+    }
+
+    /// You can change the user agent sent with each request.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().user_agent("RustExample/1.0");
+    /// ```
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut RarBgApiBuilder {
+        self.config.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// You can limit the time allowed to connect to the API.
+    ///
+    /// Ignored when a transport is given.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().connect_timeout(Duration::from_secs(5));
+    /// ```
+    pub fn connect_timeout(&mut self, connect_timeout: Duration) -> &mut RarBgApiBuilder {
+        self.config.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// You can limit the total time allowed for a request, from connecting until the body of the
+    /// response has been read. It is not reset when a part of the body is received, a slow but
+    /// steady response still times out.
+    ///
+    /// Ignored when a transport is given.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().total_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn total_timeout(&mut self, total_timeout: Duration) -> &mut RarBgApiBuilder {
+        self.config.total_timeout = Some(total_timeout);
+        self
+    }
+
+    /// You can reach the API through a HTTP, HTTPS or SOCKS proxy.
+    ///
+    /// Ignored when a transport is given.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().proxy("socks5://127.0.0.1:9050");
+    /// ```
+    pub fn proxy(&mut self, proxy: &str) -> &mut RarBgApiBuilder {
+        self.config.proxy = Some(proxy.to_string());
+        self
+    }
+
+    /// You can change the minimum delay between two requests.
+    ///
+    /// The API has a 1req/2s limit. Ignored when a rate limiter is given.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().request_delay(Duration::from_secs(2));
+    /// ```
+    pub fn request_delay(&mut self, request_delay: Duration) -> &mut RarBgApiBuilder {
+        self.config.request_delay = request_delay;
+        self
+    }
+
+    /// You can change how long a token is used before a new one is requested.
+    ///
+    /// Officially, a token is valid for 15 minutes.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().token_lifetime(Duration::from_secs(14 * 60));
+    /// ```
+    pub fn token_lifetime(&mut self, token_lifetime: Duration) -> &mut RarBgApiBuilder {
+        self.config.token_lifetime = token_lifetime;
+        self
+    }
+
+    /// You can choose whether a new token is retrieved when the API rejects the current one.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    /// use rarbg_api::token_refresh_policy::TokenRefreshPolicy;
+    ///
+    /// let rab = RarBgApiBuilder::new().token_refresh_policy(TokenRefreshPolicy::Never);
+    /// ```
+    pub fn token_refresh_policy(
+        &mut self,
+        token_refresh_policy: TokenRefreshPolicy,
+    ) -> &mut RarBgApiBuilder {
+        self.config.token_refresh_policy = token_refresh_policy;
+        self
+    }
+
+    /// By default, a search or a list without any match returns an empty Torrents.
+    ///
+    /// If false, the "No results found" error given by the API is returned instead.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().no_results_as_empty(false);
+    /// ```
+    pub fn no_results_as_empty(&mut self, no_results_as_empty: bool) -> &mut RarBgApiBuilder {
+        self.config.no_results_as_empty = no_results_as_empty;
+        self
+    }
+
+    /// You can choose how the requests that failed are sent again.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().retry_policy(RetryPolicyBuilder::new().max_attempts(5).build());
+    /// ```
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut RarBgApiBuilder {
+        self.config.retry_policy = retry_policy;
+        self
+    }
+
+    /// You can send the requests through your own transport instead of `ReqwestTransport`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    /// use rarbg_api::reqwest_transport::ReqwestTransport;
+    ///
+    /// let rab = RarBgApiBuilder::new().transport(Arc::new(ReqwestTransport::new()));
+    /// ```
+    pub fn transport(&mut self, transport: Arc<dyn Transport>) -> &mut RarBgApiBuilder {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// You can share a rate limiter between several clients using the same app_id.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    /// use rarbg_api::rate_limiter::RateLimiter;
+    ///
+    /// let rate_limiter = Arc::new(RateLimiter::new(Duration::from_secs(2)));
+    /// let rab = RarBgApiBuilder::new().rate_limiter(rate_limiter);
+    /// ```
+    pub fn rate_limiter(&mut self, rate_limiter: Arc<RateLimiter>) -> &mut RarBgApiBuilder {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// You can keep the tokens between two runs of your app.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use rarbg_api::file_token_store::FileTokenStore;
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let store = Arc::new(FileTokenStore::new("/tmp/rarbg_tokens.json"));
+    /// let rab = RarBgApiBuilder::new().token_store(store);
+    /// ```
+    pub fn token_store(&mut self, token_store: Arc<dyn TokenStore>) -> &mut RarBgApiBuilder {
+        self.token_store = Some(token_store);
+        self
+    }
+
+    /// You can share a request queue between several clients using the same app_id, so their
+    /// concurrent requests are let through one at a time by priority.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    /// use rarbg_api::request_queue::RequestQueue;
+    ///
+    /// let rab = RarBgApiBuilder::new().request_queue(Arc::new(RequestQueue::new()));
+    /// ```
+    pub fn request_queue(&mut self, request_queue: Arc<RequestQueue>) -> &mut RarBgApiBuilder {
+        self.request_queue = Some(request_queue);
+        self
+    }
+
+    /// You can choose the priority of the requests sent by the client.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::priority::Priority;
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// let rab = RarBgApiBuilder::new().priority(Priority::Background);
+    /// ```
+    pub fn priority(&mut self, priority: Priority) -> &mut RarBgApiBuilder {
+        self.priority = priority;
+        self
+    }
+
+    /// Create a new RarBgApiBuilder with default options.
+    ///
+    /// Default options are :
+    /// * torrentapi.org as base URL
+    /// * A Firefox user agent
+    /// * No connect timeout nor total timeout
+    /// * No proxy
+    /// * 5 seconds between two requests
+    /// * Tokens used for 10 minutes
+    /// * Rejected tokens refreshed once per request
+    /// * No results returned as an empty Torrents
+    /// * The default `RetryPolicy`
+    /// * `ReqwestTransport` as transport
+    /// * A rate limiter of its own
+    /// * No token store
+    /// * A request queue of its own
+    /// * Normal priority
+    pub fn new() -> Self {
+        RarBgApiBuilder {
+            config: Config::default(),
+            transport: None,
+            rate_limiter: None,
+            token_store: None,
+            request_queue: None,
+            priority: Priority::default(),
+        }
+    }
+
+    /// Construct a RarBgApi and retrieve its first token.
+    ///
+    /// # Arguments
+    ///
+    /// * `app_id` - A string slice that holds the name of your app.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy is invalid or if a token cannot be retrieve from the API.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = RarBgApiBuilder::new()
+    ///         .base_url("http://localhost:8080/pubapi_v2.php")
+    ///         .user_agent("RustExample/1.0")
+    ///         .total_timeout(Duration::from_secs(30))
+    ///         .request_delay(Duration::from_secs(2))
+    ///         .build("RustExample")
+    ///         .await
+    ///         .unwrap();
+    ///     assert_eq!(api.config().base_url(), "http://localhost:8080/pubapi_v2.php");
+    /// }
+    /// ```
+    pub async fn build(&self, app_id: &str) -> Result<RarBgApi, RarBgError> {
+        let transport = self.build_transport()?;
+        let rate_limiter = match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.clone(),
+            None => Arc::new(RateLimiter::new(self.config.request_delay)),
+        };
+        let request_queue = match &self.request_queue {
+            Some(request_queue) => request_queue.clone(),
+            None => Arc::new(RequestQueue::new()),
+        };
+        let api = RarBgApi::from_config(
+            app_id,
+            self.config.clone(),
+            transport,
+            rate_limiter,
+            self.token_store.clone(),
+            request_queue,
+        )
+        .await?;
+        Ok(api.with_priority(self.priority))
+    }
+
+    pub(crate) fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub(crate) fn build_transport(&self) -> Result<Arc<dyn Transport>, RarBgError> {
+        match &self.transport {
+            Some(transport) => Ok(transport.clone()),
+            None => Ok(Arc::new(self.reqwest_transport()?)),
+        }
+    }
+
+    fn reqwest_transport(&self) -> Result<ReqwestTransport, RarBgError> {
+        let mut builder = Client::builder();
+        if let Some(connect_timeout) = self.config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(total_timeout) = self.config.total_timeout {
+            builder = builder.timeout(total_timeout);
+        }
+        if let Some(proxy) = &self.config.proxy {
+            builder = builder.proxy(Proxy::all(proxy.as_str())?);
+        }
+        Ok(ReqwestTransport::with_client(builder.build()?))
+    }
+}
+
+impl Default for RarBgApiBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,130 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+use reqwest::Error as ReqwestError;
+use serde_json::Error as SerdeJsonError;
+
+use crate::error::Error;
+use crate::error_class::ErrorClass;
+use crate::transport::TransportError;
+
+#[derive(Debug)]
+pub enum RarBgError {
+    /// The request could not be sent or its response could not be read.
+    Transport(TransportError),
+    /// The response is neither a list of torrents nor an error given by the API.
+    Decode {
+        body: String,
+        source: SerdeJsonError,
+    },
+    /// The search query is empty or malformed, it was not sent.
+    InvalidQuery(String),
+    /// The API did not give a token.
+    Token(String),
+    /// The token could not be loaded from or saved to the token store.
+    TokenStore(Box<dyn error::Error + Send + Sync>),
+    /// The runtime of the blocking client could not be started.
+    Runtime(io::Error),
+    /// The cassette could not be read or written.
+    Cassette(Box<dyn error::Error + Send + Sync>),
+    /// The API refused the request because too many requests were made.
+    RateLimited,
+    /// The API answered with an unexpected HTTP status.
+    Http { status: u16, body: String },
+    /// The API answered with an empty body.
+    EmptyResponse,
+    /// The API returned an error.
+    Api(Error),
+    /// The request still failed after being retried by the retry policy.
+    Retried {
+        attempts: u32,
+        last_error: Box<RarBgError>,
+    },
+}
+
+impl RarBgError {
+    /// Return the kind of failure if it is transient, so the retry policy may send the request
+    /// again, otherwise None.
+    pub fn class(&self) -> Option<ErrorClass> {
+        match self {
+            RarBgError::Transport(_) => Some(ErrorClass::Transport),
+            RarBgError::RateLimited => Some(ErrorClass::RateLimited),
+            RarBgError::Http { status, .. } if *status >= 500 => Some(ErrorClass::ServerError),
+            RarBgError::EmptyResponse => Some(ErrorClass::EmptyResponse),
+            RarBgError::Api(error) if error.is_retryable() => Some(ErrorClass::Unavailable),
+            RarBgError::Retried { last_error, .. } => last_error.class(),
+            _ => None,
+        }
+    }
+
+    /// Return the number of attempts made by the retry policy before failing, the first one
+    /// included.
+    ///
+    /// Sending the request again with a refreshed token is not counted as an attempt.
+    pub fn attempts(&self) -> u32 {
+        match self {
+            RarBgError::Retried { attempts, .. } => *attempts,
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for RarBgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RarBgError::Transport(reason) => write!(f, "transport error: {}", reason),
+            RarBgError::Decode { body, source } => {
+                write!(f, "failed to decode response '{}': {}", body, source)
+            }
+            RarBgError::InvalidQuery(reason) => write!(f, "invalid search query: {}", reason),
+            RarBgError::Token(reason) => write!(f, "failed to retrieve a token: {}", reason),
+            RarBgError::TokenStore(reason) => write!(f, "token store error: {}", reason),
+            RarBgError::Runtime(reason) => write!(f, "failed to start the runtime: {}", reason),
+            RarBgError::Cassette(reason) => write!(f, "cassette error: {}", reason),
+            RarBgError::RateLimited => write!(f, "rate limited by the API"),
+            RarBgError::Http { status, body } => {
+                write!(f, "unexpected HTTP status {}: '{}'", status, body)
+            }
+            RarBgError::EmptyResponse => write!(f, "empty response from the API"),
+            RarBgError::Api(error) => write!(f, "API error: {}", error),
+            RarBgError::Retried {
+                attempts,
+                last_error,
+            } => write!(f, "failed after {} attempts: {}", attempts, last_error),
+        }
+    }
+}
+
+impl error::Error for RarBgError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            RarBgError::Transport(reason) => Some(reason.as_ref()),
+            RarBgError::Decode { source, .. } => Some(source),
+            RarBgError::TokenStore(reason) => Some(reason.as_ref()),
+            RarBgError::Runtime(reason) => Some(reason),
+            RarBgError::Cassette(reason) => Some(reason.as_ref()),
+            RarBgError::Api(error) => Some(error),
+            RarBgError::Retried { last_error, .. } => Some(last_error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ReqwestError> for RarBgError {
+    fn from(reason: ReqwestError) -> Self {
+        RarBgError::Transport(Box::new(reason))
+    }
+}
+
+impl From<TransportError> for RarBgError {
+    fn from(reason: TransportError) -> Self {
+        RarBgError::Transport(reason)
+    }
+}
+
+impl From<Error> for RarBgError {
+    fn from(error: Error) -> Self {
+        RarBgError::Api(error)
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Space out the requests made to the API.
+///
+/// A RateLimiter can be shared between several `RarBgApi` using the same app_id with
+/// `RarBgApiBuilder::rate_limiter`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Create a RateLimiter that lets one request through every `interval`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rate_limiter::RateLimiter;
+    ///
+    /// let rate_limiter = Arc::new(RateLimiter::new(Duration::from_secs(2)));
+    /// ```
+    pub fn new(interval: Duration) -> Self {
+        RateLimiter {
+            interval,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Return the minimum delay between two requests.
+    pub fn interval(&self) -> &Duration {
+        &self.interval
+    }
+
+    /// Wait until a request can be sent.
+    ///
+    /// Only the remaining time since the last request is waited, the first request is never
+    /// delayed. Waiters are let through in the order they called this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rate_limiter::RateLimiter;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let rate_limiter = RateLimiter::new(Duration::from_millis(10));
+    ///     rate_limiter.wait().await;
+    ///     rate_limiter.wait().await;
+    /// }
+    /// ```
+    pub async fn wait(&self) {
+        let slot = self.reserve();
+        tokio::time::sleep_until(slot.into()).await;
+    }
+
+    fn reserve(&self) -> Instant {
+        let mut next_slot = match self.next_slot.lock() {
+            Ok(next_slot) => next_slot,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let now = Instant::now();
+        let slot = match *next_slot {
+            Some(next) if next > now => next,
+            _ => now,
+        };
+        *next_slot = Some(slot + self.interval);
+        slot
+    }
+}
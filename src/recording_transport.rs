@@ -0,0 +1,74 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::cassette::Cassette;
+use crate::http_request::HttpRequest;
+use crate::interaction::Interaction;
+use crate::rarbg_error::RarBgError;
+use crate::transport::{Transport, TransportFuture};
+
+/// Send the requests through another transport and record them with their responses.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+/// use rarbg_api::recording_transport::RecordingTransport;
+/// use rarbg_api::reqwest_transport::ReqwestTransport;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let recorder = Arc::new(RecordingTransport::new(Arc::new(ReqwestTransport::new())));
+///     let api = RarBgApiBuilder::new()
+///         .transport(recorder.clone())
+///         .build("RustExample")
+///         .await
+///         .unwrap();
+///     let result = api.search("Rick and Morty", None).await;
+///     recorder.save("tests/cassettes/search.json").unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    cassette: Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    /// Create a RecordingTransport that sends the requests through `inner`.
+    pub fn new(inner: Arc<dyn Transport>) -> Self {
+        RecordingTransport {
+            inner,
+            cassette: Mutex::new(Cassette::new()),
+        }
+    }
+
+    /// Return the interactions recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        match self.cassette.lock() {
+            Ok(cassette) => cassette.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    /// Write the interactions recorded so far to a JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), RarBgError> {
+        self.cassette().save(path)
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self.inner.send(request).await?;
+            let interaction = Interaction::new(request, &response);
+            match self.cassette.lock() {
+                Ok(mut cassette) => cassette.push(interaction),
+                Err(poisoned) => poisoned.into_inner().push(interaction),
+            }
+            Ok(response)
+        })
+    }
+}
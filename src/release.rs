@@ -0,0 +1,233 @@
+//! Parses scene release names like "Show.Name.S02E05.1080p.WEB.H264-GROUP".
+
+use serde::{Deserialize, Serialize};
+
+use crate::audio::Audio;
+use crate::episode_code::EpisodeCode;
+use crate::hdr::Hdr;
+use crate::resolution::Resolution;
+use crate::source::Source;
+use crate::title_parser;
+use crate::video_codec::VideoCodec;
+
+/// Tags appended to release names by the sites that share them.
+const SITE_TAGS: [&str; 4] = ["rarbg", "rartv", "eztv", "ettv"];
+
+/// Words that do not describe the content but end its name. They are common words in names too,
+/// like "The Complete Guide", so they only end it after the episode code or the year.
+const OTHER_MARKERS: [&str; 16] = [
+    "internal",
+    "complete",
+    "multi",
+    "subbed",
+    "dubbed",
+    "extended",
+    "remastered",
+    "uncut",
+    "unrated",
+    "imax",
+    "limited",
+    "10bit",
+    "8bit",
+    "hybrid",
+    "amzn",
+    "nf",
+];
+
+/// What a release name tells about a torrent.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    name: String,
+    year: Option<u16>,
+    episode_code: Option<EpisodeCode>,
+    resolution: Option<Resolution>,
+    source: Option<Source>,
+    video_codec: Option<VideoCodec>,
+    hdr: Vec<Hdr>,
+    audio: Vec<Audio>,
+    group: Option<String>,
+    proper: bool,
+    repack: bool,
+}
+
+impl ReleaseInfo {
+    /// Return the name of the show or the movie, with spaces between words.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Return the year of the movie, or of the show when it tells apart shows with the same name.
+    pub fn year(&self) -> Option<&u16> {
+        self.year.as_ref()
+    }
+
+    /// Return the season and episodes of a TV release.
+    pub fn episode_code(&self) -> Option<&EpisodeCode> {
+        self.episode_code.as_ref()
+    }
+
+    /// Return the resolution.
+    pub fn resolution(&self) -> Option<&Resolution> {
+        self.resolution.as_ref()
+    }
+
+    /// Return where it was ripped from.
+    pub fn source(&self) -> Option<&Source> {
+        self.source.as_ref()
+    }
+
+    /// Return the video codec.
+    pub fn video_codec(&self) -> Option<&VideoCodec> {
+        self.video_codec.as_ref()
+    }
+
+    /// Return the high dynamic range formats, empty for SDR releases.
+    pub fn hdr(&self) -> &Vec<Hdr> {
+        &self.hdr
+    }
+
+    /// Return the audio codecs and formats.
+    pub fn audio(&self) -> &Vec<Audio> {
+        &self.audio
+    }
+
+    /// Return the group that made the release.
+    pub fn group(&self) -> Option<&String> {
+        self.group.as_ref()
+    }
+
+    /// Return true if it fixes a previous release of another group, otherwise false.
+    pub fn proper(&self) -> bool {
+        self.proper
+    }
+
+    /// Return true if it fixes a previous release of the same group, otherwise false.
+    pub fn repack(&self) -> bool {
+        self.repack
+    }
+}
+
+/// Parse a release name, like a torrent title or filename.
+///
+/// Words that are not recognized are ignored, so it never fails.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::episode_code::EpisodeCode;
+/// use rarbg_api::release;
+/// use rarbg_api::resolution::Resolution;
+/// use rarbg_api::source::Source;
+/// use rarbg_api::video_codec::VideoCodec;
+///
+/// let release = release::parse("Show.Name.S02E05.PROPER.1080p.WEB.H264-GROUP");
+/// assert_eq!(release.name(), "Show Name");
+/// assert_eq!(release.episode_code(), Some(&EpisodeCode::new(2, Some(5))));
+/// assert_eq!(release.resolution(), Some(&Resolution::FullHd1080));
+/// assert_eq!(release.source(), Some(&Source::WebDl));
+/// assert_eq!(release.video_codec(), Some(&VideoCodec::H264));
+/// assert_eq!(release.group().unwrap(), "GROUP");
+/// assert!(release.proper());
+///
+/// let movie = release::parse("Blade.Runner.2049.2017.2160p.UHD.BluRay.x265.HDR.DTS-HD.MA.5.1-SWTYBLZ");
+/// assert_eq!(movie.name(), "Blade Runner 2049");
+/// assert_eq!(movie.year(), Some(&2017));
+/// ```
+pub fn parse(title: &str) -> ReleaseInfo {
+    let mut tokens = title_parser::tokens(title);
+    while let Some(last) = tokens.last() {
+        if tokens.len() > 1 && SITE_TAGS.contains(&last.to_ascii_lowercase().as_str()) {
+            tokens.pop();
+        } else {
+            break;
+        }
+    }
+
+    let mut group = None;
+    if let Some(last) = tokens.last_mut() {
+        if Source::from_token(last).is_none() && Audio::from_token(last).is_none() {
+            if let Some((rest, name)) = last.rsplit_once('-') {
+                if !rest.is_empty() && !name.is_empty() {
+                    group = Some(name.to_string());
+                    *last = rest;
+                }
+            }
+        }
+    }
+
+    let mut release = ReleaseInfo {
+        name: String::new(),
+        year: None,
+        episode_code: None,
+        resolution: None,
+        source: None,
+        video_codec: None,
+        hdr: Vec::new(),
+        audio: Vec::new(),
+        group,
+        proper: false,
+        repack: false,
+    };
+    let mut end = tokens.len();
+    let mut described = false;
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = tokens[index];
+        let lowercase = token.to_ascii_lowercase();
+        let next = tokens.get(index + 1).copied();
+        let mut marker = true;
+        if let Some(code) = title_parser::episode_code_at(&tokens, index) {
+            release.episode_code = release.episode_code.or(Some(code));
+            described = true;
+        } else if let Some(resolution) = Resolution::from_token(token) {
+            release.resolution = release.resolution.or(Some(resolution));
+        } else if let Some(source) = Source::from_token(token) {
+            release.source = release.source.max(Some(source));
+        } else if let Some(codec) = VideoCodec::from_token(token) {
+            release.video_codec = release.video_codec.or(Some(codec));
+        } else if lowercase == "h" && (next == Some("264") || next == Some("265")) {
+            // "H.264" is split in two words.
+            release.video_codec = release
+                .video_codec
+                .or(VideoCodec::from_token(&format!("h{}", next.unwrap_or(""))));
+            index += 1;
+        } else if let Some(hdr) = Hdr::from_token(token) {
+            if !release.hdr.contains(&hdr) {
+                release.hdr.push(hdr);
+            }
+        } else if let Some(audio) = Audio::from_token(token) {
+            if !release.audio.contains(&audio) {
+                release.audio.push(audio);
+            }
+        } else if lowercase == "proper" || (lowercase == "real" && described) {
+            release.proper = true;
+        } else if lowercase == "repack" || lowercase == "rerip" {
+            release.repack = true;
+        } else {
+            marker = described && OTHER_MARKERS.contains(&lowercase.as_str());
+            described = described || (index > 0 && year(token).is_some());
+        }
+        if marker && index > 0 && end == tokens.len() {
+            end = index;
+        }
+        index += 1;
+    }
+
+    let mut name_end = end;
+    if let Some(position) = (1..end)
+        .rev()
+        .find(|position| year(tokens[*position]).is_some())
+    {
+        release.year = year(tokens[position]);
+        name_end = position;
+    }
+    release.name = tokens[..name_end].join(" ");
+    release
+}
+
+fn year(token: &str) -> Option<u16> {
+    match token.parse() {
+        Ok(year) if token.len() == 4 && (1900..=2099).contains(&year) => Some(year),
+        _ => None,
+    }
+}
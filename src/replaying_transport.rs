@@ -0,0 +1,88 @@
+use std::sync::Mutex;
+
+use crate::cassette::Cassette;
+use crate::http_request::HttpRequest;
+use crate::transport::{Transport, TransportFuture};
+
+/// Answer the requests with the responses recorded in a `Cassette`, without network access.
+///
+/// A request is answered by the first recorded interaction that matches it and was not
+/// replayed yet. Once all of them were replayed, the last one is replayed again.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::sync::Arc;
+///
+/// use rarbg_api::cassette::Cassette;
+/// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+/// use rarbg_api::replaying_transport::ReplayingTransport;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let cassette = Cassette::load("tests/cassettes/search.json").unwrap();
+///     let api = RarBgApiBuilder::new()
+///         .transport(Arc::new(ReplayingTransport::new(cassette)))
+///         .build("RustExample")
+///         .await
+///         .unwrap();
+///     let result = api.search("Rick and Morty", None).await;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReplayingTransport {
+    cassette: Cassette,
+    replayed: Mutex<Vec<bool>>,
+}
+
+impl ReplayingTransport {
+    /// Create a ReplayingTransport that replays the given cassette.
+    pub fn new(cassette: Cassette) -> Self {
+        let replayed = vec![false; cassette.interactions().len()];
+        ReplayingTransport {
+            cassette,
+            replayed: Mutex::new(replayed),
+        }
+    }
+
+    /// Return the cassette being replayed.
+    pub fn cassette(&self) -> &Cassette {
+        &self.cassette
+    }
+}
+
+impl Transport for ReplayingTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let mut replayed = match self.replayed.lock() {
+                Ok(replayed) => replayed,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            let matching: Vec<usize> = self
+                .cassette
+                .interactions()
+                .iter()
+                .enumerate()
+                .filter(|(_, interaction)| interaction.matches(request))
+                .map(|(index, _)| index)
+                .collect();
+            let index = matching
+                .iter()
+                .find(|index| !replayed[**index])
+                .or_else(|| matching.last());
+            match index {
+                Some(index) => {
+                    replayed[*index] = true;
+                    Ok(self.cassette.interactions()[*index].response())
+                }
+                None => Err(format!(
+                    "No interaction recorded for {} {} {:?}",
+                    request.method(),
+                    request.url(),
+                    request.query_pairs()
+                )
+                .into()),
+            }
+        })
+    }
+}
@@ -0,0 +1,164 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::{Mutex, MutexGuard};
+
+use tokio::sync::oneshot::{self, Receiver, Sender};
+
+use crate::priority::Priority;
+
+/// Let the requests of concurrent tasks through one at a time.
+///
+/// Requests with a higher priority are let through first, requests with the same priority are
+/// let through in the order they were queued. A queued request is cancelled by dropping its
+/// future.
+#[derive(Debug, Default)]
+pub struct RequestQueue {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    busy: bool,
+    next_ticket: u64,
+    waiters: BinaryHeap<Waiter>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    priority: Priority,
+    ticket: u64,
+    sender: Sender<()>,
+}
+
+/// Allow its holder to send a request, the next queued request is let through when it is
+/// dropped.
+#[derive(Debug)]
+pub struct QueuePermit<'a> {
+    queue: &'a RequestQueue,
+}
+
+struct Waiting<'a> {
+    queue: &'a RequestQueue,
+    receiver: Option<Receiver<()>>,
+}
+
+impl RequestQueue {
+    /// Create an empty RequestQueue.
+    pub fn new() -> Self {
+        RequestQueue::default()
+    }
+
+    /// Return the number of requests waiting to be let through.
+    pub fn len(&self) -> usize {
+        self.state()
+            .waiters
+            .iter()
+            .filter(|waiter| !waiter.sender.is_closed())
+            .count()
+    }
+
+    /// Return true if no request is waiting to be let through, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Wait until the request is let through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::priority::Priority;
+    /// use rarbg_api::request_queue::RequestQueue;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let queue = RequestQueue::new();
+    ///     let permit = queue.acquire(Priority::Interactive).await;
+    ///     // Send the request, the next one is let through when permit is dropped.
+    /// }
+    /// ```
+    pub async fn acquire(&self, priority: Priority) -> QueuePermit<'_> {
+        let receiver = {
+            let mut state = self.state();
+            if !state.busy {
+                state.busy = true;
+                return QueuePermit { queue: self };
+            }
+            let (sender, receiver) = oneshot::channel();
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiters.push(Waiter {
+                priority,
+                ticket,
+                sender,
+            });
+            receiver
+        };
+        let mut waiting = Waiting {
+            queue: self,
+            receiver: Some(receiver),
+        };
+        if let Some(receiver) = waiting.receiver.as_mut() {
+            let _ = receiver.await;
+        }
+        waiting.receiver = None;
+        QueuePermit { queue: self }
+    }
+
+    fn release(&self) {
+        let mut state = self.state();
+        while let Some(waiter) = state.waiters.pop() {
+            if waiter.sender.send(()).is_ok() {
+                return;
+            }
+        }
+        state.busy = false;
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl Drop for QueuePermit<'_> {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+/* A request cancelled right after being let through hands its turn to the next one. */
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        if let Some(mut receiver) = self.receiver.take() {
+            receiver.close();
+            if receiver.try_recv().is_ok() {
+                self.queue.release();
+            }
+        }
+    }
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.ticket.cmp(&self.ticket))
+    }
+}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
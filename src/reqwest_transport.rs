@@ -0,0 +1,42 @@
+use reqwest::Client;
+
+use crate::http_request::HttpRequest;
+use crate::http_response::HttpResponse;
+use crate::transport::{Transport, TransportFuture};
+
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport backed by a default `reqwest::Client`.
+    pub fn new() -> Self {
+        ReqwestTransport {
+            client: Client::new(),
+        }
+    }
+
+    /// Create a transport backed by your own `reqwest::Client`.
+    pub fn with_client(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method().clone(), request.url())
+                .query(request.query_pairs());
+            for (name, value) in request.headers() {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let body = response.text().await?;
+            Ok(HttpResponse::new(status, body.as_str()))
+        })
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// The vertical resolution of a video release, from the lowest to the highest.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Resolution {
+    Sd,
+    Hd720,
+    FullHd1080,
+    UltraHd2160,
+}
+
+impl Resolution {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Resolution::Sd => "480p",
+            Resolution::Hd720 => "720p",
+            Resolution::FullHd1080 => "1080p",
+            Resolution::UltraHd2160 => "2160p",
+        }
+    }
+
+    pub(crate) fn from_token(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "360p" | "480p" | "480i" | "576p" | "576i" | "sd" => Some(Resolution::Sd),
+            "720p" => Some(Resolution::Hd720),
+            "1080p" | "1080i" => Some(Resolution::FullHd1080),
+            "2160p" | "4k" | "uhd" => Some(Resolution::UltraHd2160),
+            _ => None,
+        }
+    }
+}
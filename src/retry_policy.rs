@@ -0,0 +1,91 @@
+use std::cmp::min;
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::error_class::ErrorClass;
+use crate::rarbg_error::RarBgError;
+
+/// How many times and how often a request that failed is sent again.
+///
+/// The delay before the n-th retry is `base_delay * 2^(n - 1)`, capped to `max_delay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) base_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) jitter: bool,
+    pub(crate) retryable: Vec<ErrorClass>,
+}
+
+impl RetryPolicy {
+    /// Create a RetryPolicy that never sends a request again.
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Return the maximum number of times a request is sent.
+    pub fn max_attempts(&self) -> &u32 {
+        &self.max_attempts
+    }
+
+    /// Return the delay before the first retry.
+    pub fn base_delay(&self) -> &Duration {
+        &self.base_delay
+    }
+
+    /// Return the longest delay between two retries.
+    pub fn max_delay(&self) -> &Duration {
+        &self.max_delay
+    }
+
+    /// Return true if the delays are randomly shortened by up to a half, otherwise false.
+    pub fn jitter(&self) -> &bool {
+        &self.jitter
+    }
+
+    /// Return the kinds of failure that are retried.
+    pub fn retryable(&self) -> &Vec<ErrorClass> {
+        &self.retryable
+    }
+
+    /// Return true if the request that failed with `error` should be sent again, otherwise false.
+    pub fn is_retryable(&self, error: &RarBgError) -> bool {
+        match error.class() {
+            Some(class) => self.retryable.contains(&class),
+            None => false,
+        }
+    }
+
+    /// Return the delay before the `retry`-th retry, starting from 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = min(self.base_delay.saturating_mul(factor), self.max_delay);
+        if self.jitter {
+            delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retryable: vec![
+                ErrorClass::Transport,
+                ErrorClass::RateLimited,
+                ErrorClass::ServerError,
+                ErrorClass::EmptyResponse,
+                ErrorClass::Unavailable,
+            ],
+        }
+    }
+}
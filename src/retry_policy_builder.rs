@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use crate::error_class::ErrorClass;
+use crate::retry_policy::RetryPolicy;
+
+#[derive(Clone, Debug)]
+pub struct RetryPolicyBuilder {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retryable: Vec<ErrorClass>,
+}
+
+impl RetryPolicyBuilder {
+    /// You can choose how many times a request is sent at most, the first attempt included.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rpb = RetryPolicyBuilder::new().max_attempts(5);
+    /// ```
+    pub fn max_attempts(&mut self, max_attempts: u32) -> &mut RetryPolicyBuilder {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// You can choose the delay before the first retry, it doubles at each retry.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rpb = RetryPolicyBuilder::new().base_delay(Duration::from_secs(1));
+    /// ```
+    pub fn base_delay(&mut self, base_delay: Duration) -> &mut RetryPolicyBuilder {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// You can choose the longest delay between two retries.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rpb = RetryPolicyBuilder::new().max_delay(Duration::from_secs(10));
+    /// ```
+    pub fn max_delay(&mut self, max_delay: Duration) -> &mut RetryPolicyBuilder {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// You can randomly shorten each delay by up to a half to spread the retries.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rpb = RetryPolicyBuilder::new().jitter(false);
+    /// ```
+    pub fn jitter(&mut self, jitter: bool) -> &mut RetryPolicyBuilder {
+        self.jitter = jitter;
+        self
+    }
+
+    /// You can choose which kinds of failure are retried.
+    ///
+    /// # Example
+    /// ```
+    /// use rarbg_api::error_class::ErrorClass;
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rpb = RetryPolicyBuilder::new().retryable(vec![ErrorClass::RateLimited]);
+    /// ```
+    pub fn retryable(&mut self, retryable: Vec<ErrorClass>) -> &mut RetryPolicyBuilder {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Create a new RetryPolicyBuilder with default options.
+    ///
+    /// Default options are :
+    /// * 3 attempts
+    /// * 2 seconds before the first retry
+    /// * 30 seconds at most between two retries
+    /// * Jitter enabled
+    /// * Every kind of failure retried
+    pub fn new() -> Self {
+        let default = RetryPolicy::default();
+        RetryPolicyBuilder {
+            max_attempts: default.max_attempts,
+            base_delay: default.base_delay,
+            max_delay: default.max_delay,
+            jitter: default.jitter,
+            retryable: default.retryable,
+        }
+    }
+
+    /// Construct an immutable RetryPolicy that can be use with RarBgApiBuilder.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::error_class::ErrorClass;
+    /// use rarbg_api::retry_policy::RetryPolicy;
+    /// use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+    ///
+    /// let rp: RetryPolicy = RetryPolicyBuilder::new()
+    ///                        .max_attempts(5)
+    ///                        .base_delay(Duration::from_secs(1))
+    ///                        .max_delay(Duration::from_secs(10))
+    ///                        .jitter(false)
+    ///                        .retryable(vec![ErrorClass::RateLimited, ErrorClass::ServerError])
+    ///                        .build();
+    /// assert_eq!(*rp.max_attempts(), 5);
+    /// assert_eq!(rp.delay(1), Duration::from_secs(1));
+    /// assert_eq!(rp.delay(3), Duration::from_secs(4));
+    /// assert_eq!(rp.delay(10), Duration::from_secs(10));
+    /// assert_eq!(*rp.retryable(), vec![ErrorClass::RateLimited, ErrorClass::ServerError]);
+    /// ```
+    pub fn build(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.max_attempts,
+            base_delay: self.base_delay,
+            max_delay: self.max_delay,
+            jitter: self.jitter,
+            retryable: self.retryable.clone(),
+        }
+    }
+}
+
+impl Default for RetryPolicyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::score_entry::ScoreEntry;
+
+/// The score of a torrent according to a QualityProfile, explained.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreBreakdown {
+    entries: Vec<ScoreEntry>,
+    rejections: Vec<String>,
+}
+
+impl ScoreBreakdown {
+    /// Return the sum of the points of every entry, saturating at the bounds of an i32.
+    pub fn total(&self) -> i32 {
+        self.entries
+            .iter()
+            .fold(0, |total: i32, entry| total.saturating_add(entry.points()))
+    }
+
+    /// Return the points given, in the order they were computed.
+    pub fn entries(&self) -> &Vec<ScoreEntry> {
+        &self.entries
+    }
+
+    /// Return why the torrent cannot be chosen, empty if it can.
+    pub fn rejections(&self) -> &Vec<String> {
+        &self.rejections
+    }
+
+    /// Return true if the torrent cannot be chosen whatever its score, otherwise false.
+    pub fn is_rejected(&self) -> bool {
+        !self.rejections.is_empty()
+    }
+
+    pub(crate) fn add(&mut self, reason: String, points: i32) {
+        if points != 0 {
+            self.entries.push(ScoreEntry::new(reason, points));
+        }
+    }
+
+    pub(crate) fn reject(&mut self, reason: String) {
+        self.rejections.push(reason);
+    }
+}
+
+impl fmt::Display for ScoreBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} points", self.total())?;
+        for entry in self.entries.iter() {
+            write!(f, "\n  {:+} {}", entry.points(), entry.reason())?;
+        }
+        for rejection in self.rejections.iter() {
+            write!(f, "\n  rejected: {}", rejection)?;
+        }
+        Ok(())
+    }
+}
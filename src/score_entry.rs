@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Points given to a torrent by a QualityProfile, and why.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreEntry {
+    reason: String,
+    points: i32,
+}
+
+impl ScoreEntry {
+    pub(crate) fn new(reason: String, points: i32) -> Self {
+        ScoreEntry { reason, points }
+    }
+
+    /// Return what the points are given for, like "resolution 1080p".
+    pub fn reason(&self) -> &str {
+        self.reason.as_str()
+    }
+
+    /// Return the points, negative for a penalty.
+    pub fn points(&self) -> i32 {
+        self.points
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+use crate::episode_info::EpisodeInfo;
+use crate::imdb_id::ImdbId;
+use crate::tmdb_id::TmdbId;
+use crate::tvdb_id::TvdbId;
+
+/// An id that torrents can be searched by.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SearchId {
+    /// An IMDB id, like "tt2861424".
+    Imdb(ImdbId),
+    /// A TVDB id, like "275274".
+    Tvdb(TvdbId),
+    /// A TMDB id, like "60625".
+    Tmdb(TmdbId),
+}
+
+impl SearchId {
+    /// Return the query key used by the API for this kind of id.
+    pub fn key(&self) -> &str {
+        match self {
+            SearchId::Imdb(_) => "search_imdb",
+            SearchId::Tvdb(_) => "search_tvdb",
+            SearchId::Tmdb(_) => "search_tmdb",
+        }
+    }
+
+    /// Return the id as sent to the API.
+    pub fn value(&self) -> String {
+        match self {
+            SearchId::Imdb(id) => id.to_string(),
+            SearchId::Tvdb(id) => id.to_string(),
+            SearchId::Tmdb(id) => id.to_string(),
+        }
+    }
+
+    /// Return false if the episode info holds an id of the same kind that differs, otherwise true.
+    pub(crate) fn matches(&self, episode_info: &EpisodeInfo) -> bool {
+        match self {
+            SearchId::Imdb(id) => episode_info.imdb_id().is_none_or(|other| *id == other),
+            SearchId::Tvdb(id) => episode_info.tvdb_id().is_none_or(|other| *id == other),
+            SearchId::Tmdb(id) => episode_info.tmdb_id().is_none_or(|other| *id == other),
+        }
+    }
+}
+
+impl From<ImdbId> for SearchId {
+    fn from(id: ImdbId) -> Self {
+        SearchId::Imdb(id)
+    }
+}
+
+impl From<TvdbId> for SearchId {
+    fn from(id: TvdbId) -> Self {
+        SearchId::Tvdb(id)
+    }
+}
+
+impl From<TmdbId> for SearchId {
+    fn from(id: TmdbId) -> Self {
+        SearchId::Tmdb(id)
+    }
+}
+
+impl fmt::Display for SearchId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.key(), self.value())
+    }
+}
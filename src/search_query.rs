@@ -0,0 +1,81 @@
+use crate::rarbg_error::RarBgError;
+use crate::search_id::SearchId;
+
+/// What to search for: a free-text string, an id, or both.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::imdb_id::ImdbId;
+/// use rarbg_api::search_query::SearchQuery;
+///
+/// // Rick and Morty, season 2, episode 3
+/// let imdb_id: ImdbId = "tt2861424".parse().unwrap();
+/// let query = SearchQuery::new()
+///     .with_id(imdb_id)
+///     .with_string("S02E03");
+/// assert!(query.validate().is_ok());
+/// assert!(SearchQuery::new().validate().is_err());
+/// ```
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct SearchQuery {
+    search_string: Option<String>,
+    search_id: Option<SearchId>,
+}
+
+impl SearchQuery {
+    /// Create an empty SearchQuery.
+    pub fn new() -> Self {
+        SearchQuery::default()
+    }
+
+    /// Search torrents whose name matches `search_string`.
+    pub fn with_string(mut self, search_string: &str) -> Self {
+        self.search_string = Some(search_string.to_string());
+        self
+    }
+
+    /// Search torrents of the movie or the show identified by `search_id`.
+    pub fn with_id<T: Into<SearchId>>(mut self, search_id: T) -> Self {
+        self.search_id = Some(search_id.into());
+        self
+    }
+
+    /// Return the free-text string.
+    pub fn search_string(&self) -> Option<&String> {
+        self.search_string.as_ref()
+    }
+
+    /// Return the id.
+    pub fn search_id(&self) -> Option<&SearchId> {
+        self.search_id.as_ref()
+    }
+
+    /// Verifies that the query has an id or a non-blank string.
+    pub fn validate(&self) -> Result<(), RarBgError> {
+        if let Some(search_string) = &self.search_string {
+            if search_string.trim().is_empty() {
+                return Err(RarBgError::InvalidQuery(
+                    "the search string is blank".to_string(),
+                ));
+            }
+        }
+        if self.search_string.is_none() && self.search_id.is_none() {
+            return Err(RarBgError::InvalidQuery(
+                "a search string or an id is required".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn query_pairs(&self) -> Vec<(&str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(search_string) = &self.search_string {
+            pairs.push(("search_string", search_string.clone()));
+        }
+        if let Some(search_id) = &self.search_id {
+            pairs.push((search_id.key(), search_id.value()));
+        }
+        pairs
+    }
+}
@@ -1,18 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Default, Hash, PartialEq, Eq)]
 pub enum SortBy {
     Seeders,
     Leechers,
+    #[default]
     Last,
 }
 
-impl Default for SortBy {
-    fn default() -> Self {
-        SortBy::Last
-    }
-}
-
 impl SortBy {
     pub fn as_str(&self) -> &str {
         match self {
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::torrent::Torrent;
+
+/// A property of torrents that they can be sorted by on the client side.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub enum SortKey {
+    Size,
+    PubDate,
+    Seeders,
+    Leechers,
+    /// Seeders divided by leechers, or by one when there is no leecher.
+    Ratio,
+}
+
+impl SortKey {
+    /// Return the value of the property, or None if the torrent does not have it.
+    pub(crate) fn value(&self, torrent: &Torrent) -> Option<f64> {
+        match self {
+            SortKey::Size => torrent.size().map(|size| *size as f64),
+            SortKey::PubDate => torrent.pub_date().map(|date| date.timestamp() as f64),
+            SortKey::Seeders => torrent.seeders().map(|seeders| f64::from(*seeders)),
+            SortKey::Leechers => torrent.leechers().map(|leechers| f64::from(*leechers)),
+            SortKey::Ratio => match (torrent.seeders(), torrent.leechers()) {
+                (Some(seeders), Some(leechers)) => {
+                    Some(f64::from(*seeders) / f64::from((*leechers).max(1)))
+                }
+                _ => None,
+            },
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// Where a video release was ripped from, from the lowest to the highest quality.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Source {
+    Cam,
+    Telesync,
+    Dvd,
+    Hdtv,
+    WebRip,
+    WebDl,
+    BluRay,
+}
+
+impl Source {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Source::Cam => "CAM",
+            Source::Telesync => "TS",
+            Source::Dvd => "DVD",
+            Source::Hdtv => "HDTV",
+            Source::WebRip => "WEBRip",
+            Source::WebDl => "WEB-DL",
+            Source::BluRay => "BluRay",
+        }
+    }
+
+    pub(crate) fn from_token(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "cam" | "camrip" | "hdcam" => Some(Source::Cam),
+            "ts" | "hdts" | "telesync" => Some(Source::Telesync),
+            "dvd" | "dvdrip" | "dvdr" | "dvd5" | "dvd9" => Some(Source::Dvd),
+            "hdtv" | "pdtv" | "sdtv" => Some(Source::Hdtv),
+            "webrip" | "web-rip" => Some(Source::WebRip),
+            "web" | "webdl" | "web-dl" => Some(Source::WebDl),
+            "bluray" | "blu-ray" | "bdrip" | "brrip" | "bdremux" | "remux" | "bd" => {
+                Some(Source::BluRay)
+            }
+            _ => None,
+        }
+    }
+}
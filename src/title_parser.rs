@@ -0,0 +1,55 @@
+//! Extracts the information packed in release names like "Show.Name.S03E07.1080p.WEB.H264-GROUP".
+
+use crate::episode_code::EpisodeCode;
+
+/// Split a release name into its words.
+pub(crate) fn tokens(title: &str) -> Vec<&str> {
+    title
+        .split(['.', '_', '[', ']', '(', ')'])
+        .flat_map(|part| part.split_whitespace())
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// Return true if the words of `phrase` follow each other in the title, whatever the case.
+///
+/// Words joined by a dash are also compared one by one, so "GROUP" is found in "x264-GROUP" and
+/// "WEB-DL" in "WEB-DL", but "CAM" is not found in "Scam".
+pub(crate) fn contains_words(title: &str, phrase: &str) -> bool {
+    let words = |text| -> Vec<String> {
+        tokens(text)
+            .into_iter()
+            .flat_map(|token| token.split('-'))
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect()
+    };
+    let phrase = words(phrase);
+    !phrase.is_empty()
+        && words(title)
+            .windows(phrase.len())
+            .any(|window| window == phrase.as_slice())
+}
+
+/// Return the season and episodes named by the word at `index`, like "S03E07", "3x07" or
+/// "Season 3".
+pub(crate) fn episode_code_at(tokens: &[&str], index: usize) -> Option<EpisodeCode> {
+    let token = tokens[index];
+    // "S03E07-GROUP" happens when the episode code is the last word of the name.
+    let code = token.split('-').next().unwrap_or(token);
+    if let Ok(code) = token.parse().or_else(|_| code.parse()) {
+        return Some(code);
+    }
+    if token.eq_ignore_ascii_case("season") {
+        if let Some(season) = tokens.get(index + 1).and_then(|next| next.parse().ok()) {
+            return Some(EpisodeCode::new(season, None));
+        }
+    }
+    None
+}
+
+/// Return the season and episodes named in a release name.
+pub(crate) fn episode_code(title: &str) -> Option<EpisodeCode> {
+    let tokens = tokens(title);
+    (0..tokens.len()).find_map(|index| episode_code_at(&tokens, index))
+}
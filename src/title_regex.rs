@@ -0,0 +1,76 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A regular expression matched against torrent titles, serialized as its pattern.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::title_regex::TitleRegex;
+///
+/// let regex: TitleRegex = r"(?i)\bS0[1-3]E\d+\b".parse().unwrap();
+/// assert!(regex.is_match("Rick.and.Morty.S02E03.1080p.WEB.H264-CAKES"));
+/// assert!("(".parse::<TitleRegex>().is_err());
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TitleRegex(Regex);
+
+impl TitleRegex {
+    /// Return the pattern.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Return true if the pattern matches somewhere in `title`, otherwise false.
+    pub fn is_match(&self, title: &str) -> bool {
+        self.0.is_match(title)
+    }
+}
+
+impl FromStr for TitleRegex {
+    type Err = regex::Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Regex::new(pattern).map(TitleRegex)
+    }
+}
+
+impl TryFrom<String> for TitleRegex {
+    type Error = regex::Error;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        pattern.parse()
+    }
+}
+
+impl From<TitleRegex> for String {
+    fn from(regex: TitleRegex) -> Self {
+        regex.as_str().to_string()
+    }
+}
+
+impl PartialEq for TitleRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TitleRegex {}
+
+impl Hash for TitleRegex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Display for TitleRegex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
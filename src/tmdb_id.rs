@@ -0,0 +1,17 @@
+use crate::numeric_id::numeric_id;
+
+numeric_id! {
+    /// A TMDB id, like "60625".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::tmdb_id::TmdbId;
+    ///
+    /// let id: TmdbId = "60625".parse().unwrap();
+    /// assert_eq!(id.value(), 60625);
+    /// assert!("tt60625".parse::<TmdbId>().is_err());
+    /// ```
+    TmdbId,
+    "TMDB"
+}
@@ -1,15 +1,20 @@
 extern crate reqwest;
 
-use std::collections::HashMap;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
-use crate::ENDPOINT;
-use crate::USER_AGENT;
+use crate::config::Config;
+use crate::http_request::HttpRequest;
+use crate::http_response::HttpResponse;
+use crate::rarbg_api_builder::RarBgApiBuilder;
+use crate::rarbg_error::RarBgError;
+use crate::transport::Transport;
+use crate::TOKEN_LIFETIME;
 
-use reqwest::{Client, Response};
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct Token {
     value: String,
     created_at: SystemTime,
@@ -25,7 +30,7 @@ impl Token {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let token = Token::new("RustExample").await;
+    ///     let token = Token::new("RustExample").await.unwrap();
     ///     let value = token.value();
     /// }
     /// ```
@@ -42,7 +47,7 @@ impl Token {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let token = Token::new("RustExample").await;
+    ///     let token = Token::new("RustExample").await.unwrap();
     ///     let time_of_creation = token.created_at();
     /// }
     /// ```
@@ -53,9 +58,9 @@ impl Token {
     /// Create a Token with the value obtained from the API.
     /// This token can be use to make requests to the API.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if a token cannot be retrieve from the API.
+    /// Returns an error if a token cannot be retrieve from the API.
     ///
     /// # Example
     ///
@@ -64,40 +69,97 @@ impl Token {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let token = Token::new("RustExample").await;
+    ///     let token = Token::new("RustExample").await.unwrap();
     /// }
     /// ```
-    pub async fn new(app_id: &str) -> Self {
-        let response = Token::get(app_id).await;
-        let content = Token::parse(response).await;
-        let value = content.get("token");
-        match value {
-            Some(token) => Token {
-                value: token.clone(),
-                created_at: SystemTime::now(),
-            },
-            None => panic!("Failed to retrieve a token from RARBG API."),
-        }
+    pub async fn new(app_id: &str) -> Result<Self, RarBgError> {
+        Token::with_builder(&RarBgApiBuilder::new(), app_id).await
+    }
+
+    /// Create a Token with the value obtained from the API configured by a RarBgApiBuilder.
+    ///
+    /// The token is requested from its base URL with its user agent, through its transport or
+    /// else through a client with its timeouts and proxy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the proxy is invalid or if a token cannot be retrieve from the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+    /// use rarbg_api::token::Token;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut builder = RarBgApiBuilder::new();
+    ///     builder.user_agent("RustExample/1.0").total_timeout(Duration::from_secs(30));
+    ///     let token = Token::with_builder(&builder, "RustExample").await.unwrap();
+    /// }
+    /// ```
+    pub async fn with_builder(builder: &RarBgApiBuilder, app_id: &str) -> Result<Self, RarBgError> {
+        let transport = builder.build_transport()?;
+        Token::fetch(app_id, transport.as_ref(), builder.config()).await
     }
 
-    async fn get(app_id: &str) -> Response {
-        let client: Client = Client::builder().user_agent(USER_AGENT).build().unwrap();
-        let response = client
-            .get(ENDPOINT)
-            .query(&[("get_token", "get_token")])
-            .query(&[("app_id", app_id)])
-            .send()
-            .await;
-        match response {
-            Ok(response) => response,
-            Err(reason) => panic!("{}", reason),
+    /// Create a Token with the value obtained from the API through the given transport.
+    ///
+    /// The token is requested from torrentapi.org with the default user agent, use
+    /// `Token::with_builder` to change them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a token cannot be retrieve from the API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rarbg_api::reqwest_transport::ReqwestTransport;
+    /// use rarbg_api::token::Token;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let transport = ReqwestTransport::new();
+    ///     let token = Token::with_transport("RustExample", &transport).await.unwrap();
+    /// }
+    /// ```
+    pub async fn with_transport(
+        app_id: &str,
+        transport: &dyn Transport,
+    ) -> Result<Self, RarBgError> {
+        Token::fetch(app_id, transport, &Config::default()).await
+    }
+
+    pub(crate) async fn fetch(
+        app_id: &str,
+        transport: &dyn Transport,
+        config: &Config,
+    ) -> Result<Self, RarBgError> {
+        let request = HttpRequest::new(Method::GET, config.base_url())
+            .header("User-Agent", config.user_agent())
+            .query("get_token", "get_token")
+            .query("app_id", app_id);
+        let response = transport.send(&request).await?;
+        let content = Token::parse(response)?;
+        match content.get("token").and_then(|token| token.as_str()) {
+            Some(token) => Ok(Token {
+                value: token.to_string(),
+                created_at: SystemTime::now(),
+            }),
+            None => Err(RarBgError::Token(Value::Object(content).to_string())),
         }
     }
 
-    async fn parse(response: Response) -> HashMap<String, String> {
-        match response.json().await {
-            Ok(json) => json,
-            Err(reason) => panic!("{}", reason),
+    fn parse(response: HttpResponse) -> Result<Map<String, Value>, RarBgError> {
+        match serde_json::from_str(response.body()) {
+            Ok(json) => Ok(json),
+            Err(source) => Err(RarBgError::Decode {
+                body: response.body().to_string(),
+                source,
+            }),
         }
     }
 
@@ -111,17 +173,34 @@ impl Token {
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let token = Token::new("RustExample").await;
+    ///     let token = Token::new("RustExample").await.unwrap();
     ///     assert!(token.is_valid(), "Token should be valid !");
     /// }
     /// ```
     pub fn is_valid(&self) -> bool {
+        self.is_valid_for(Duration::from_secs(TOKEN_LIFETIME))
+    }
+
+    /// Verifies that the token was created less than `lifetime` ago.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use rarbg_api::token::Token;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let token = Token::new("RustExample").await.unwrap();
+    ///     assert!(token.is_valid_for(Duration::from_secs(14 * 60)), "Token should be valid !");
+    /// }
+    /// ```
+    pub fn is_valid_for(&self, lifetime: Duration) -> bool {
         let sys_time = SystemTime::now();
         let difference = sys_time.duration_since(self.created_at);
         match difference {
-            Ok(duration) => {
-                duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) * 1e-9 < 600.0
-            } // < 10 min
+            Ok(duration) => duration < lifetime,
             Err(_) => false,
         }
     }
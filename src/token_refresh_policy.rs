@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// What the client does when the API says that its token is invalid or expired.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Default, Hash, PartialEq, Eq)]
+pub enum TokenRefreshPolicy {
+    /// Return the error given by the API.
+    Never,
+    /// Retrieve a new token and send the request once more.
+    #[default]
+    RetryOnce,
+}
@@ -0,0 +1,18 @@
+use std::fmt::Debug;
+
+use crate::rarbg_error::RarBgError;
+use crate::token::Token;
+
+/// Keep the tokens of your apps between two runs.
+///
+/// `RarBgApi` loads the token of its app when it is created and saves every new token it
+/// retrieves from the API.
+pub trait TokenStore: Debug + Send + Sync {
+    /// Return the last token saved for the app, or None if there is none.
+    fn load(&self, app_id: &str) -> Result<Option<Token>, RarBgError>;
+
+    /// Save the token of the app, replacing the previous one.
+    ///
+    /// `RarBgApi` keeps using the token if it cannot be saved.
+    fn save(&self, app_id: &str, token: &Token) -> Result<(), RarBgError>;
+}
@@ -4,13 +4,20 @@ use std::fmt;
 use std::fs::File;
 use std::io;
 use std::io::Write;
+use std::time::SystemTime;
 
 use chrono::{DateTime, Utc};
 use serde::de::{Error, Unexpected};
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::category::Category;
+use crate::episode_code::EpisodeCode;
 use crate::episode_info::EpisodeInfo;
+use crate::magnet::Magnet;
+use crate::parse_magnet_error::ParseMagnetError;
+use crate::release;
+use crate::release::ReleaseInfo;
+use crate::title_parser;
 
 use self::uuid::Uuid;
 
@@ -28,6 +35,8 @@ pub struct Torrent {
     #[serde(default, deserialize_with = "bool_from_int")]
     ranked: Option<bool>,
     info_page: Option<String>,
+    #[serde(skip)]
+    pub(crate) fetched_at: Option<SystemTime>,
 }
 
 impl Torrent {
@@ -55,6 +64,34 @@ impl Torrent {
         self.download.as_str()
     }
 
+    /// Return the magnet link parsed into its info hash, display name, trackers and size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the magnet link is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::torrent::Torrent;
+    /// use serde_json::json;
+    ///
+    /// let torrent: Torrent = serde_json::from_value(json!({
+    ///     "title": "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    ///     "category": "TV HD Episodes",
+    ///     "download": "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567\
+    ///                  &dn=Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES\
+    ///                  &tr=http%3A%2F%2Ftracker.trackerfix.com%3A80%2Fannounce"
+    /// }))
+    /// .unwrap();
+    /// let magnet = torrent.magnet().unwrap();
+    /// assert_eq!(magnet.display_name().unwrap(), "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES");
+    /// assert_eq!(magnet.trackers().len(), 1);
+    /// ```
+    pub fn magnet(&self) -> Result<Magnet, ParseMagnetError> {
+        self.download.parse()
+    }
+
     /// Return the number of seeders available.
     ///
     /// Only available when `format` is set to `Format::JsonExtended`.
@@ -92,6 +129,78 @@ impl Torrent {
         self.episode_info.as_ref()
     }
 
+    /// Return what the title, or else the filename, tells about the release.
+    ///
+    /// Return None if the torrent has neither a title nor a filename.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::resolution::Resolution;
+    /// use rarbg_api::torrent::Torrent;
+    /// use serde_json::json;
+    ///
+    /// let torrent: Torrent = serde_json::from_value(json!({
+    ///     "title": "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    ///     "category": "TV HD Episodes",
+    ///     "download": "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567"
+    /// }))
+    /// .unwrap();
+    /// let release = torrent.release_info().unwrap();
+    /// assert_eq!(release.name(), "Rick and Morty");
+    /// assert_eq!(release.resolution(), Some(&Resolution::FullHd1080));
+    /// assert_eq!(release.group().unwrap(), "CAKES");
+    /// ```
+    pub fn release_info(&self) -> Option<ReleaseInfo> {
+        self.title
+            .as_ref()
+            .or(self.filename.as_ref())
+            .map(|name| release::parse(name))
+    }
+
+    /// Return the season and episodes of a TV release.
+    ///
+    /// They are read from the title or the filename, then from the episode info since it
+    /// describes a single episode even for a season pack.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::episode_code::EpisodeCode;
+    /// use rarbg_api::torrent::Torrent;
+    /// use serde_json::json;
+    ///
+    /// let torrent: Torrent = serde_json::from_value(json!({
+    ///     "title": "Rick.and.Morty.S06.1080p.WEB.H264-CAKES",
+    ///     "category": "TV HD Episodes",
+    ///     "download": "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567"
+    /// }))
+    /// .unwrap();
+    /// assert_eq!(torrent.episode_code(), Some(EpisodeCode::new(6, None)));
+    /// assert!(torrent.is_season_pack());
+    /// ```
+    pub fn episode_code(&self) -> Option<EpisodeCode> {
+        self.title
+            .as_ref()
+            .or(self.filename.as_ref())
+            .and_then(|name| title_parser::episode_code(name))
+            .or_else(|| self.episode_info.as_ref()?.episode_code())
+    }
+
+    /// Return true if it's a whole season of a TV show, otherwise false.
+    pub fn is_season_pack(&self) -> bool {
+        self.episode_code()
+            .map(|code| code.is_season_pack())
+            .unwrap_or(false)
+    }
+
+    /// Return true if it's several episodes of a TV show but not a whole season, otherwise false.
+    pub fn is_multi_episode(&self) -> bool {
+        self.episode_code()
+            .map(|code| code.is_multi_episode())
+            .unwrap_or(false)
+    }
+
     /// Return true if it's a scene, rarbg or rartv releases, otherwise false.
     ///
     /// Only available when `format` is set to `Format::JsonExtended`.
@@ -106,6 +215,30 @@ impl Torrent {
         self.info_page.as_ref()
     }
 
+    /// Return when the torrent was retrieved from the API.
+    ///
+    /// Return None if the torrent was not retrieved from the API, like when it is read from a file.
+    pub fn fetched_at(&self) -> Option<&SystemTime> {
+        self.fetched_at.as_ref()
+    }
+
+    /// Return the key identifying the release: its info hash, or its magnet link if malformed.
+    pub(crate) fn dedup_key(&self) -> String {
+        match self.magnet() {
+            Ok(magnet) => magnet.info_hash_hex(),
+            Err(_) => self.download.clone(),
+        }
+    }
+
+    /// Take the seeders and leechers of `other` if they were retrieved after these ones.
+    pub(crate) fn refresh_counts(&mut self, other: &Torrent) {
+        if other.fetched_at >= self.fetched_at {
+            self.seeders = other.seeders.or(self.seeders);
+            self.leechers = other.leechers.or(self.leechers);
+            self.fetched_at = other.fetched_at;
+        }
+    }
+
     /// Export the torrent to a magnet file using its title, filename or UUID as filename.
     ///
     /// # Arguments
@@ -0,0 +1,171 @@
+use std::ops::Not;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::resolution::Resolution;
+use crate::title_regex::TitleRegex;
+use crate::torrent::Torrent;
+use crate::video_codec::VideoCodec;
+
+/// A rule selecting torrents on the client side, for what the API cannot filter.
+///
+/// Rules are combined with `and`, `or` and `!`, and can be stored in configuration files
+/// since they are serializable.
+///
+/// Titles are read from `Torrent::title`, or else `Torrent::filename`. A rule on a property
+/// that a torrent does not have, like its size with `Format::Json`, does not match it.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::resolution::Resolution;
+/// use rarbg_api::torrent::Torrent;
+/// use rarbg_api::torrent_filter::TorrentFilter;
+/// use serde_json::json;
+///
+/// let filter = TorrentFilter::MinResolution(Resolution::FullHd1080)
+///     .and(TorrentFilter::Size { min: None, max: Some(2 * 1024 * 1024 * 1024) })
+///     .and(!TorrentFilter::Groups(vec!["YIFY".to_string()]));
+///
+/// let json = serde_json::to_string(&filter).unwrap();
+/// let stored: TorrentFilter = serde_json::from_str(&json).unwrap();
+/// assert_eq!(stored, filter);
+///
+/// let torrent: Torrent = serde_json::from_value(json!({
+///     "title": "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+///     "category": "TV HD Episodes",
+///     "download": "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567",
+///     "size": 1073741824
+/// }))
+/// .unwrap();
+/// assert!(filter.matches(&torrent));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TorrentFilter {
+    /// Matches if every rule matches, or always if there is none.
+    All(Vec<TorrentFilter>),
+    /// Matches if at least one rule matches, or never if there is none.
+    Any(Vec<TorrentFilter>),
+    /// Matches if the rule does not.
+    Not(Box<TorrentFilter>),
+    /// Matches if the size in bytes is within the bounds, which are inclusive.
+    Size {
+        min: Option<u128>,
+        max: Option<u128>,
+    },
+    /// Matches if the publication date is within the bounds, which are inclusive.
+    Published {
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    },
+    /// Matches if it was published at most this many seconds ago. A number of seconds too large
+    /// for a duration means no limit.
+    PublishedWithin(i64),
+    /// Matches if the title contains this text, whatever the case.
+    TitleContains(String),
+    /// Matches if the title does not contain this text, whatever the case.
+    TitleExcludes(String),
+    /// Matches if the regular expression matches the title.
+    TitleMatches(TitleRegex),
+    /// Matches if the release group is one of these, whatever the case.
+    Groups(Vec<String>),
+    /// Matches if the release group is unknown or is none of these, whatever the case.
+    ExcludeGroups(Vec<String>),
+    /// Matches if the resolution is one of these.
+    Resolutions(Vec<Resolution>),
+    /// Matches if the resolution is this one or a higher one.
+    MinResolution(Resolution),
+    /// Matches if the video codec is one of these.
+    VideoCodecs(Vec<VideoCodec>),
+}
+
+impl TorrentFilter {
+    /// Return a rule matching if both this rule and `other` match.
+    pub fn and(self, other: TorrentFilter) -> TorrentFilter {
+        match self {
+            TorrentFilter::All(mut filters) => {
+                filters.push(other);
+                TorrentFilter::All(filters)
+            }
+            filter => TorrentFilter::All(vec![filter, other]),
+        }
+    }
+
+    /// Return a rule matching if this rule or `other` matches.
+    pub fn or(self, other: TorrentFilter) -> TorrentFilter {
+        match self {
+            TorrentFilter::Any(mut filters) => {
+                filters.push(other);
+                TorrentFilter::Any(filters)
+            }
+            filter => TorrentFilter::Any(vec![filter, other]),
+        }
+    }
+
+    /// Return true if the torrent is selected by the rule, otherwise false.
+    pub fn matches(&self, torrent: &Torrent) -> bool {
+        let title = torrent.title().or_else(|| torrent.filename());
+        match self {
+            TorrentFilter::All(filters) => filters.iter().all(|filter| filter.matches(torrent)),
+            TorrentFilter::Any(filters) => filters.iter().any(|filter| filter.matches(torrent)),
+            TorrentFilter::Not(filter) => !filter.matches(torrent),
+            TorrentFilter::Size { min, max } => torrent.size().is_some_and(|size| {
+                min.is_none_or(|min| *size >= min) && max.is_none_or(|max| *size <= max)
+            }),
+            TorrentFilter::Published { after, before } => torrent.pub_date().is_some_and(|date| {
+                after.is_none_or(|after| *date >= after)
+                    && before.is_none_or(|before| *date <= before)
+            }),
+            TorrentFilter::PublishedWithin(seconds) => match Duration::try_seconds(*seconds) {
+                Some(window) => torrent
+                    .pub_date()
+                    .is_some_and(|date| Utc::now() - *date <= window),
+                None => torrent.pub_date().is_some(),
+            },
+            TorrentFilter::TitleContains(text) => {
+                title.is_some_and(|title| contains_ignore_case(title, text))
+            }
+            TorrentFilter::TitleExcludes(text) => {
+                title.is_some_and(|title| !contains_ignore_case(title, text))
+            }
+            TorrentFilter::TitleMatches(regex) => title.is_some_and(|title| regex.is_match(title)),
+            TorrentFilter::Groups(groups) => torrent
+                .release_info()
+                .and_then(|release| release.group().cloned())
+                .is_some_and(|group| groups.iter().any(|g| g.eq_ignore_ascii_case(&group))),
+            TorrentFilter::ExcludeGroups(groups) => torrent
+                .release_info()
+                .and_then(|release| release.group().cloned())
+                .is_none_or(|group| !groups.iter().any(|g| g.eq_ignore_ascii_case(&group))),
+            TorrentFilter::Resolutions(resolutions) => torrent
+                .release_info()
+                .and_then(|release| release.resolution().copied())
+                .is_some_and(|resolution| resolutions.contains(&resolution)),
+            TorrentFilter::MinResolution(minimum) => torrent
+                .release_info()
+                .and_then(|release| release.resolution().copied())
+                .is_some_and(|resolution| resolution >= *minimum),
+            TorrentFilter::VideoCodecs(codecs) => torrent
+                .release_info()
+                .and_then(|release| release.video_codec().copied())
+                .is_some_and(|codec| codecs.contains(&codec)),
+        }
+    }
+}
+
+impl Not for TorrentFilter {
+    type Output = TorrentFilter;
+
+    fn not(self) -> Self::Output {
+        match self {
+            TorrentFilter::Not(filter) => *filter,
+            filter => TorrentFilter::Not(Box::new(filter)),
+        }
+    }
+}
+
+fn contains_ignore_case(title: &str, text: &str) -> bool {
+    title.to_lowercase().contains(&text.to_lowercase())
+}
@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::sort_key::SortKey;
+use crate::sort_order::SortOrder;
+use crate::torrent::Torrent;
+
+type Score = Box<dyn Fn(&Torrent) -> f64 + Send + Sync>;
+
+enum Criterion {
+    Key(SortKey),
+    Score(Score),
+}
+
+/// How to sort torrents on the client side, by several keys in turn.
+///
+/// Torrents equal on the first key are sorted by the second one, and so on. Torrents equal
+/// on every key keep their order. Torrents that lack a key, like the size with `Format::Json`,
+/// come after the others whatever the order.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::sort_key::SortKey;
+/// use rarbg_api::sort_order::SortOrder;
+/// use rarbg_api::torrent_sort::TorrentSort;
+///
+/// // Most seeded first, then the smallest, then the ones with "PROPER" in their title.
+/// let sort = TorrentSort::new()
+///     .then(SortKey::Seeders, SortOrder::Descending)
+///     .then(SortKey::Size, SortOrder::Ascending)
+///     .then_score(
+///         |torrent| match torrent.title() {
+///             Some(title) if title.contains("PROPER") => 1.0,
+///             _ => 0.0,
+///         },
+///         SortOrder::Descending,
+///     );
+/// ```
+#[derive(Default)]
+pub struct TorrentSort {
+    criteria: Vec<(Criterion, SortOrder)>,
+}
+
+impl TorrentSort {
+    /// Create a TorrentSort that keeps the order.
+    pub fn new() -> Self {
+        TorrentSort::default()
+    }
+
+    /// Sort by `key` the torrents that are equal on the previous keys.
+    pub fn then(mut self, key: SortKey, order: SortOrder) -> Self {
+        self.criteria.push((Criterion::Key(key), order));
+        self
+    }
+
+    /// Sort by a score of your own the torrents that are equal on the previous keys.
+    ///
+    /// A score that is not a number (NaN) is handled like a missing key.
+    pub fn then_score<F>(mut self, score: F, order: SortOrder) -> Self
+    where
+        F: Fn(&Torrent) -> f64 + Send + Sync + 'static,
+    {
+        self.criteria
+            .push((Criterion::Score(Box::new(score)), order));
+        self
+    }
+
+    /// Sort the torrents, keeping the order of the ones that are equal on every key.
+    pub(crate) fn sort(&self, torrents: &mut Vec<Torrent>) {
+        let mut keyed: Vec<(Vec<Option<f64>>, Torrent)> = torrents
+            .drain(..)
+            .map(|torrent| (self.values(&torrent), torrent))
+            .collect();
+        keyed.sort_by(|(left, _), (right, _)| self.compare(left, right));
+        torrents.extend(keyed.into_iter().map(|(_, torrent)| torrent));
+    }
+
+    fn values(&self, torrent: &Torrent) -> Vec<Option<f64>> {
+        self.criteria
+            .iter()
+            .map(|(criterion, _)| match criterion {
+                Criterion::Key(key) => key.value(torrent),
+                Criterion::Score(score) => Some(score(torrent)),
+            })
+            .map(|value| value.filter(|value| !value.is_nan()))
+            .collect()
+    }
+
+    fn compare(&self, left: &[Option<f64>], right: &[Option<f64>]) -> Ordering {
+        for ((_, order), (left, right)) in self.criteria.iter().zip(left.iter().zip(right.iter())) {
+            let ordering = match (left, right) {
+                (Some(left), Some(right)) => match order {
+                    SortOrder::Ascending => left.total_cmp(right),
+                    SortOrder::Descending => right.total_cmp(left),
+                },
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl fmt::Debug for TorrentSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let criteria: Vec<String> = self
+            .criteria
+            .iter()
+            .map(|(criterion, order)| match criterion {
+                Criterion::Key(key) => format!("{:?} {:?}", key, order),
+                Criterion::Score(_) => format!("Score {:?}", order),
+            })
+            .collect();
+        f.debug_struct("TorrentSort")
+            .field("criteria", &criteria)
+            .finish()
+    }
+}
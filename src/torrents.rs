@@ -1,15 +1,203 @@
+use std::collections::HashMap;
+
+use crate::quality_profile::QualityProfile;
+use crate::score_breakdown::ScoreBreakdown;
+use crate::sort_key::SortKey;
+use crate::sort_order::SortOrder;
 use crate::torrent::Torrent;
+use crate::torrent_filter::TorrentFilter;
+use crate::torrent_sort::TorrentSort;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Torrents {
-    torrent_results: Vec<Torrent>,
+    pub(crate) torrent_results: Vec<Torrent>,
+    #[serde(skip)]
+    pub(crate) attempts: u32,
 }
 
 impl Torrents {
+    /// Create a list of torrents, like the ones retrieved from several requests.
+    pub fn new(torrents: Vec<Torrent>) -> Self {
+        Torrents {
+            torrent_results: torrents,
+            attempts: 0,
+        }
+    }
+
     /// Return a list of torrents.
     pub fn torrents(&self) -> &Vec<Torrent> {
         &self.torrent_results
     }
+
+    /// Return the number of attempts made by the retry policy to get these torrents, the first
+    /// one included. Sending the request again with a refreshed token is not counted.
+    ///
+    /// Return 0 if these torrents were not retrieved from the API.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Add the torrents of `other` that are not already there, and update the seeders and
+    /// leechers of the ones that are with the most recently retrieved counts.
+    ///
+    /// Torrents are the same release if their magnet links have the same info hash. The order
+    /// is kept, new releases are added at the end, and the attempts are summed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::torrent::Torrent;
+    /// use rarbg_api::torrents::Torrents;
+    /// use serde_json::json;
+    ///
+    /// let torrent = |episode: u32, seeders: u32| -> Torrent {
+    ///     serde_json::from_value(json!({
+    ///         "title": format!("Rick.and.Morty.S06E{:02}.1080p.WEB.H264-CAKES", episode),
+    ///         "category": "TV HD Episodes",
+    ///         "download": format!("magnet:?xt=urn:btih:{:040x}", episode),
+    ///         "seeders": seeders
+    ///     }))
+    ///     .unwrap()
+    /// };
+    /// let mut torrents = Torrents::new(vec![torrent(1, 10)]);
+    /// torrents.merge(Torrents::new(vec![torrent(1, 42), torrent(2, 7)]));
+    /// assert_eq!(torrents.torrents().len(), 2);
+    /// assert_eq!(torrents.torrents()[0].seeders(), Some(&42));
+    /// ```
+    pub fn merge(&mut self, other: Torrents) {
+        self.attempts += other.attempts;
+        self.torrent_results.extend(other.torrent_results);
+        self.dedup();
+    }
+
+    /// Remove the torrents that are the same release as a previous one, keeping the most
+    /// recently retrieved seeders and leechers.
+    pub fn dedup(&mut self) {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut unique: Vec<Torrent> = Vec::with_capacity(self.torrent_results.len());
+        for torrent in self.torrent_results.drain(..) {
+            let key = torrent.dedup_key();
+            match positions.get(&key) {
+                Some(position) => unique[*position].refresh_counts(&torrent),
+                None => {
+                    positions.insert(key, unique.len());
+                    unique.push(torrent);
+                }
+            }
+        }
+        self.torrent_results = unique;
+    }
+
+    /// Return the torrents selected by the filter, in the same order.
+    pub fn filter(&self, filter: &TorrentFilter) -> Torrents {
+        Torrents {
+            torrent_results: self
+                .torrent_results
+                .iter()
+                .filter(|torrent| filter.matches(torrent))
+                .cloned()
+                .collect(),
+            attempts: self.attempts,
+        }
+    }
+
+    /// Keep only the torrents selected by the filter.
+    pub fn retain(&mut self, filter: &TorrentFilter) {
+        self.torrent_results
+            .retain(|torrent| filter.matches(torrent));
+    }
+
+    /// Sort the torrents on the client side, by several keys in turn.
+    ///
+    /// See `TorrentSort` for how ties and missing keys are handled.
+    pub fn sort(&mut self, sort: &TorrentSort) {
+        sort.sort(&mut self.torrent_results);
+    }
+
+    /// Sort the torrents on the client side by a single key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::sort_key::SortKey;
+    /// use rarbg_api::sort_order::SortOrder;
+    /// use rarbg_api::torrent::Torrent;
+    /// use rarbg_api::torrents::Torrents;
+    /// use serde_json::json;
+    ///
+    /// let torrent = |title: &str, seeders: u32| -> Torrent {
+    ///     serde_json::from_value(json!({
+    ///         "title": title,
+    ///         "category": "TV HD Episodes",
+    ///         "download": "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567",
+    ///         "seeders": seeders
+    ///     }))
+    ///     .unwrap()
+    /// };
+    /// let mut torrents = Torrents::new(vec![
+    ///     torrent("Rick.and.Morty.S06E01.720p.WEB.H264-CAKES", 3),
+    ///     torrent("Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES", 42),
+    /// ]);
+    /// torrents.sort_by_key(SortKey::Seeders, SortOrder::Descending);
+    /// assert_eq!(torrents.torrents()[0].seeders(), Some(&42));
+    /// ```
+    pub fn sort_by_key(&mut self, key: SortKey, order: SortOrder) {
+        self.sort(&TorrentSort::new().then(key, order));
+    }
+
+    /// Return the torrent with the highest score according to the profile, and its score
+    /// breakdown.
+    ///
+    /// Rejected torrents are never chosen, and on a tie the first torrent wins. Return None if
+    /// every torrent is rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+    /// use rarbg_api::resolution::Resolution;
+    /// use rarbg_api::torrent::Torrent;
+    /// use rarbg_api::torrents::Torrents;
+    /// use serde_json::json;
+    ///
+    /// let torrent = |title: &str| -> Torrent {
+    ///     serde_json::from_value(json!({
+    ///         "title": title,
+    ///         "category": "TV HD Episodes",
+    ///         "download": "magnet:?xt=urn:btih:0123456789abcdef0123456789abcdef01234567",
+    ///         "seeders": 42
+    ///     }))
+    ///     .unwrap()
+    /// };
+    /// let torrents = Torrents::new(vec![
+    ///     torrent("Rick.and.Morty.S06E01.720p.WEB.H264-CAKES"),
+    ///     torrent("Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES"),
+    ///     torrent("Rick.and.Morty.S06E01.HDCAM.1080p.x264-NOPE"),
+    /// ]);
+    /// let profile = QualityProfileBuilder::new()
+    ///     .resolution(Resolution::FullHd1080, 100)
+    ///     .resolution(Resolution::Hd720, 50)
+    ///     .forbidden_word("HDCAM")
+    ///     .build();
+    ///
+    /// let (best, breakdown) = torrents.best_match(&profile).unwrap();
+    /// assert_eq!(best.title().unwrap(), "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES");
+    /// assert_eq!(breakdown.total(), 116);
+    /// ```
+    pub fn best_match(&self, profile: &QualityProfile) -> Option<(&Torrent, ScoreBreakdown)> {
+        let mut best: Option<(&Torrent, ScoreBreakdown)> = None;
+        for torrent in self.torrent_results.iter() {
+            let breakdown = profile.score(torrent);
+            if breakdown.is_rejected() {
+                continue;
+            }
+            match &best {
+                Some((_, best_breakdown)) if best_breakdown.total() >= breakdown.total() => {}
+                _ => best = Some((torrent, breakdown)),
+            }
+        }
+        best
+    }
 }
@@ -0,0 +1,42 @@
+use std::error::Error;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::http_request::HttpRequest;
+use crate::http_response::HttpResponse;
+
+/// Error returned by a transport when a request cannot be sent or its response cannot be read.
+pub type TransportError = Box<dyn Error + Send + Sync>;
+
+/// Future returned by `Transport::send`.
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, TransportError>> + Send + 'a>>;
+
+/// Send the requests prepared by `RarBgApi` and `Token`.
+///
+/// `ReqwestTransport` is used by default, implement this trait to record, replay or instrument
+/// the requests made to the API.
+///
+/// # Example
+///
+/// ```
+/// use rarbg_api::http_request::HttpRequest;
+/// use rarbg_api::http_response::HttpResponse;
+/// use rarbg_api::transport::{Transport, TransportFuture};
+///
+/// #[derive(Debug)]
+/// struct NoResults;
+///
+/// impl Transport for NoResults {
+///     fn send<'a>(&'a self, _request: &'a HttpRequest) -> TransportFuture<'a> {
+///         Box::pin(async {
+///             Ok(HttpResponse::new(200, r#"{"error":"No results found","error_code":20}"#))
+///         })
+///     }
+/// }
+/// ```
+pub trait Transport: Debug + Send + Sync {
+    /// Send the request and return the status code and the body of the response.
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a>;
+}
@@ -0,0 +1,17 @@
+use crate::numeric_id::numeric_id;
+
+numeric_id! {
+    /// A TVRage id, like "33381".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::tv_rage_id::TvRageId;
+    ///
+    /// let id: TvRageId = "33381".parse().unwrap();
+    /// assert_eq!(id.value(), 33381);
+    /// assert!("tt33381".parse::<TvRageId>().is_err());
+    /// ```
+    TvRageId,
+    "TVRage"
+}
@@ -0,0 +1,17 @@
+use crate::numeric_id::numeric_id;
+
+numeric_id! {
+    /// A TVDB id, like "275274".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rarbg_api::tvdb_id::TvdbId;
+    ///
+    /// let id: TvdbId = "275274".parse().unwrap();
+    /// assert_eq!(id.value(), 275274);
+    /// assert!("tt275274".parse::<TvdbId>().is_err());
+    /// ```
+    TvdbId,
+    "TVDB"
+}
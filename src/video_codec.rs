@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// The video codec of a release.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VideoCodec {
+    Xvid,
+    H264,
+    H265,
+    Av1,
+}
+
+impl VideoCodec {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VideoCodec::Xvid => "XviD",
+            VideoCodec::H264 => "H264",
+            VideoCodec::H265 => "H265",
+            VideoCodec::Av1 => "AV1",
+        }
+    }
+
+    pub(crate) fn from_token(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "xvid" | "divx" => Some(VideoCodec::Xvid),
+            "x264" | "h264" | "avc" => Some(VideoCodec::H264),
+            "x265" | "h265" | "hevc" => Some(VideoCodec::H265),
+            "av1" => Some(VideoCodec::Av1),
+            _ => None,
+        }
+    }
+}
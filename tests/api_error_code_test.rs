@@ -0,0 +1,33 @@
+use rarbg_api::api_error_code::ApiErrorCode;
+
+#[test]
+fn map_known_codes() {
+    let codes = [
+        (1, ApiErrorCode::NoTokenSet, true, false, false),
+        (2, ApiErrorCode::InvalidToken, true, false, false),
+        (4, ApiErrorCode::TokenExpired, true, false, false),
+        (5, ApiErrorCode::TooManyRequests, false, true, false),
+        (10, ApiErrorCode::ImdbNotFound, false, false, false),
+        (20, ApiErrorCode::NoResults, false, false, true),
+    ];
+    for (number, code, invalid_token, retryable, no_results) in codes.iter() {
+        assert_eq!(ApiErrorCode::from(*number), *code);
+        assert_eq!(code.as_u8(), *number);
+        assert_eq!(code.to_string(), number.to_string());
+        assert_eq!(code.is_invalid_token(), *invalid_token, "{:?}", code);
+        assert_eq!(code.is_retryable(), *retryable, "{:?}", code);
+        assert_eq!(code.is_no_results(), *no_results, "{:?}", code);
+    }
+}
+
+#[test]
+fn map_unknown_codes() {
+    for number in [0, 3, 6, 8, 14, 21, 255].iter() {
+        let code = ApiErrorCode::from(*number);
+        assert_eq!(code, ApiErrorCode::Unknown(*number));
+        assert_eq!(code.as_u8(), *number);
+        assert!(!code.is_invalid_token());
+        assert!(!code.is_retryable());
+        assert!(!code.is_no_results());
+    }
+}
@@ -0,0 +1,89 @@
+extern crate rarbg_api;
+
+use std::env::temp_dir;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rarbg_api::api_parameters_builder::ApiParametersBuilder;
+use rarbg_api::cassette::Cassette;
+use rarbg_api::format::Format;
+use rarbg_api::mock::{sample_torrent, MockServer};
+use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+use rarbg_api::recording_transport::RecordingTransport;
+use rarbg_api::replaying_transport::ReplayingTransport;
+use rarbg_api::reqwest_transport::ReqwestTransport;
+use rarbg_api::retry_policy::RetryPolicy;
+use rarbg_api::tvdb_id::TvdbId;
+
+#[tokio::test]
+async fn replay_recorded_search() {
+    let path = temp_dir().join("rarbg_api_replay_recorded_search.json");
+    let recorded = {
+        let server = MockServer::start().await;
+        server.set_torrents(vec![sample_torrent(
+            "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+        )]);
+        let recorder = Arc::new(RecordingTransport::new(Arc::new(ReqwestTransport::new())));
+        let api = RarBgApiBuilder::new()
+            .base_url(server.url().as_str())
+            .request_delay(Duration::from_millis(1))
+            .transport(recorder.clone())
+            .build("cassette-test")
+            .await
+            .unwrap();
+        let torrents = api.search("Rick and Morty", None).await.unwrap();
+        recorder.save(&path).unwrap();
+        torrents
+    };
+
+    let cassette = Cassette::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(cassette.interactions().len(), 2);
+    let api = RarBgApiBuilder::new()
+        .request_delay(Duration::from_millis(1))
+        .retry_policy(RetryPolicy::never())
+        .transport(Arc::new(ReplayingTransport::new(cassette)))
+        .build("another-app")
+        .await
+        .unwrap();
+    let replayed = api.search("Rick and Morty", None).await.unwrap();
+
+    assert_eq!(replayed.torrents().len(), recorded.torrents().len());
+    assert_eq!(
+        replayed.torrents()[0].download(),
+        recorded.torrents()[0].download()
+    );
+    assert!(api.search("Futurama", None).await.is_err());
+}
+
+#[tokio::test]
+async fn replay_odd_episode_info() {
+    let cassette = Cassette::load("tests/cassettes/odd_episode_info.json").unwrap();
+    let api = RarBgApiBuilder::new()
+        .request_delay(Duration::from_millis(1))
+        .transport(Arc::new(ReplayingTransport::new(cassette)))
+        .build("cassette-test")
+        .await
+        .unwrap();
+    let parameters = ApiParametersBuilder::new()
+        .format(Format::JsonExtended)
+        .build();
+
+    let result = api
+        .search_by_tvdb(&TvdbId::new(275274), Some(&parameters))
+        .await
+        .unwrap();
+
+    let torrent = &result.torrents()[0];
+    assert!(torrent.is_season_pack());
+    let episode_info = torrent.episode_info().unwrap();
+    assert_eq!(episode_info.air_date(), None);
+    assert_eq!(episode_info.episode_number(), None);
+    assert_eq!(episode_info.tvrage_id(), None);
+    assert_eq!(episode_info.tvdb_id(), Some(TvdbId::new(275274)));
+    assert_eq!(
+        episode_info.imdb_id().map(|id| id.to_string()),
+        Some("tt2861424".to_string())
+    );
+}
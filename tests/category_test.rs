@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use rarbg_api::category::Category;
+
+/* The match has no wildcard, so a new variant does not compile until it is given the next index,
+and the test below fails until it is also added to `Category::all`. */
+const VARIANTS: usize = 25;
+
+fn index(category: Category) -> usize {
+    match category {
+        Category::Xxx => 0,
+        Category::MoviesXvid => 1,
+        Category::MoviesXvid720 => 2,
+        Category::MoviesX264 => 3,
+        Category::MoviesX2641080 => 4,
+        Category::MoviesX264720 => 5,
+        Category::MoviesX2643d => 6,
+        Category::MoviesX2644k => 7,
+        Category::MoviesX2651080 => 8,
+        Category::MoviesX2654k => 9,
+        Category::MoviesX2654kHdr => 10,
+        Category::MoviesFullBd => 11,
+        Category::MoviesBdRemux => 12,
+        Category::TvEpisodes => 13,
+        Category::TvHdEpisodes => 14,
+        Category::TvUhdEpisodes => 15,
+        Category::MusicMp3 => 16,
+        Category::MusicFlac => 17,
+        Category::GamesPcIso => 18,
+        Category::GamesPcRip => 19,
+        Category::GamesPs3 => 20,
+        Category::GamesXbox360 => 21,
+        Category::SoftwarePcIso => 22,
+        Category::GamesPs4 => 23,
+        Category::Ebooks => 24,
+    }
+}
+
+#[test]
+fn all_lists_every_category_once() {
+    let all = Category::all();
+    let indexes: HashSet<usize> = all.iter().map(|category| index(*category)).collect();
+    let ids: HashSet<&str> = all.iter().map(|category| category.as_str()).collect();
+
+    assert_eq!(all.len(), VARIANTS);
+    assert_eq!(indexes, (0..VARIANTS).collect());
+    assert_eq!(ids.len(), VARIANTS);
+}
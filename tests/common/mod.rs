@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use rarbg_api::torrent::Torrent;
+use rarbg_api::torrents::Torrents;
+use serde_json::{json, Value};
+
+/// Return the titles of the torrents, in order.
+pub fn titles(torrents: &Torrents) -> Vec<&str> {
+    torrents
+        .torrents()
+        .iter()
+        .map(|torrent| torrent.title().unwrap().as_str())
+        .collect()
+}
+
+/// Build a torrent as given by the API with the `json_extended` format, changing only the
+/// fields that matter to a test.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SampleTorrentBuilder {
+    torrent: Value,
+}
+
+impl SampleTorrentBuilder {
+    /// Start from an episode of Rick and Morty with this title, whose info hash depends
+    /// only on the title.
+    pub fn new(title: &str) -> SampleTorrentBuilder {
+        let mut hasher = DefaultHasher::new();
+        title.hash(&mut hasher);
+        let high = hasher.finish();
+        high.hash(&mut hasher);
+        let info_hash = (u128::from(high) << 64) | u128::from(hasher.finish());
+        SampleTorrentBuilder {
+            torrent: json!({
+                "title": title,
+                "category": "TV HD Episodes",
+                "download": format!(
+                    "magnet:?xt=urn:btih:{:040x}&dn={}&tr=http%3A%2F%2Ftracker.trackerfix.com%3A80%2Fannounce",
+                    info_hash,
+                    title
+                ),
+                "seeders": 42,
+                "leechers": 7,
+                "size": 1_073_741_824u64,
+                "pubdate": "2022-09-05 03:12:40 +0000",
+                "episode_info": {
+                    "imdb": "tt2861424",
+                    "tvrage": "33381",
+                    "tvdb": "275274",
+                    "themoviedb": "60625",
+                    "airdate": "2022-09-04",
+                    "epnum": "1",
+                    "seasonnum": "6",
+                    "title": "Solaricks"
+                },
+                "ranked": 1,
+                "info_page": "https://torrentapi.org/redirect_to_info.php?token=mock&p=1_1_1_1"
+            }),
+        }
+    }
+
+    /// Set the category name, like "Movies/x265/4k/HDR".
+    pub fn category(&mut self, category: &str) -> &mut SampleTorrentBuilder {
+        self.torrent["category"] = category.into();
+        self
+    }
+
+    /// Set the number of seeders, or remove it.
+    pub fn seeders(&mut self, seeders: Option<u32>) -> &mut SampleTorrentBuilder {
+        self.torrent["seeders"] = seeders.into();
+        self
+    }
+
+    /// Set the number of leechers, or remove it.
+    pub fn leechers(&mut self, leechers: Option<u32>) -> &mut SampleTorrentBuilder {
+        self.torrent["leechers"] = leechers.into();
+        self
+    }
+
+    /// Set the size in bytes.
+    pub fn size(&mut self, size: u64) -> &mut SampleTorrentBuilder {
+        self.torrent["size"] = size.into();
+        self
+    }
+
+    /// Set the publication date, like "2022-09-05 03:12:40 +0000".
+    pub fn pub_date(&mut self, pub_date: &str) -> &mut SampleTorrentBuilder {
+        self.torrent["pubdate"] = pub_date.into();
+        self
+    }
+
+    /// Return the torrent as JSON, to be given by the mock server.
+    pub fn json(&self) -> Value {
+        self.torrent.clone()
+    }
+
+    /// Return the torrent.
+    pub fn build(&self) -> Torrent {
+        serde_json::from_value(self.json()).expect("Failed to parse the sample torrent.")
+    }
+}
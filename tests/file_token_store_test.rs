@@ -0,0 +1,62 @@
+extern crate rarbg_api;
+
+use std::env::temp_dir;
+use std::fs;
+use std::path::PathBuf;
+
+use rarbg_api::file_token_store::FileTokenStore;
+use rarbg_api::rarbg_error::RarBgError;
+use rarbg_api::token::Token;
+use rarbg_api::token_store::TokenStore;
+use serde_json::json;
+
+fn token(value: &str) -> Token {
+    serde_json::from_value(json!({
+        "value": value,
+        "created_at": {"secs_since_epoch": 1_700_000_000, "nanos_since_epoch": 0}
+    }))
+    .unwrap()
+}
+
+fn path(name: &str) -> PathBuf {
+    let path = temp_dir().join(name);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn load_saved_tokens() {
+    let path = path("rarbg_api_load_saved_tokens.json");
+    let store = FileTokenStore::new(&path);
+
+    assert_eq!(store.load("first-app").unwrap(), None);
+
+    store.save("first-app", &token("abc")).unwrap();
+    store.save("second-app", &token("def")).unwrap();
+    store.save("first-app", &token("ghi")).unwrap();
+
+    let reopened = FileTokenStore::new(&path);
+    assert_eq!(reopened.load("first-app").unwrap(), Some(token("ghi")));
+    assert_eq!(reopened.load("second-app").unwrap(), Some(token("def")));
+    assert_eq!(reopened.load("third-app").unwrap(), None);
+    assert!(!temp_dir()
+        .join("rarbg_api_load_saved_tokens.json.tmp")
+        .exists());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn replace_corrupt_file() {
+    let path = path("rarbg_api_replace_corrupt_file.json");
+    fs::write(&path, "{\"first-app\": {\"value\": \"abc\"").unwrap();
+    let store = FileTokenStore::new(&path);
+
+    match store.load("first-app") {
+        Err(RarBgError::TokenStore(_)) => {}
+        other => panic!("Expected a token store error, got {:?}", other),
+    }
+
+    store.save("first-app", &token("def")).unwrap();
+    assert_eq!(store.load("first-app").unwrap(), Some(token("def")));
+    fs::remove_file(&path).unwrap();
+}
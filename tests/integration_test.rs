@@ -1,27 +1,75 @@
 extern crate rarbg_api;
 
+mod common;
+
+use std::env::temp_dir;
+use std::fs;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rarbg_api::api_error_code::ApiErrorCode;
 use rarbg_api::api_parameters_builder::ApiParametersBuilder;
 use rarbg_api::category::Category;
+use rarbg_api::file_token_store::FileTokenStore;
 use rarbg_api::format::Format;
 use rarbg_api::limit::Limit;
-use rarbg_api::RarBgApi;
+use rarbg_api::memory_token_store::MemoryTokenStore;
+use rarbg_api::mock::{sample_torrent, MockResponse, MockServer};
+use rarbg_api::rarbg_api_builder::RarBgApiBuilder;
+use rarbg_api::rarbg_error::RarBgError;
+use rarbg_api::retry_policy_builder::RetryPolicyBuilder;
+use rarbg_api::search_id::SearchId;
+use rarbg_api::search_query::SearchQuery;
 use rarbg_api::sort_by::SortBy;
+use rarbg_api::token::Token;
+use rarbg_api::token_store::TokenStore;
+use rarbg_api::RarBgApi;
+
+use common::SampleTorrentBuilder;
+
+fn builder(server: &MockServer) -> RarBgApiBuilder {
+    let mut builder = RarBgApiBuilder::new();
+    builder
+        .base_url(server.url().as_str())
+        .request_delay(Duration::from_millis(1))
+        .retry_policy(
+            RetryPolicyBuilder::new()
+                .base_delay(Duration::from_millis(1))
+                .build(),
+        );
+    builder
+}
 
 #[tokio::test]
 async fn search_torrents_and_export_first_torrent() {
-    let mut api = RarBgApi::new("integration-test").await;
+    let server = MockServer::start().await;
+    server.set_torrents(vec![sample_torrent(
+        "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    )]);
+    let api = builder(&server).build("integration-test").await.unwrap();
 
     assert_eq!(api.app_id(), "integration-test");
 
     let parameters = ApiParametersBuilder::new()
         .limit(Limit::TwentyFive)
-        .categories(vec![Category::TvUhdEpisodes, Category::TvHdEpisodes, Category::TvEpisodes])
+        .categories(vec![
+            Category::TvUhdEpisodes,
+            Category::TvHdEpisodes,
+            Category::TvEpisodes,
+        ])
         .sort_by(SortBy::Seeders)
         .build();
     // Specified options
     assert_eq!(*parameters.limit(), Limit::TwentyFive);
-    assert_eq!(*parameters.categories().unwrap(), vec![Category::TvUhdEpisodes, Category::TvHdEpisodes, Category::TvEpisodes]);
+    assert_eq!(
+        *parameters.categories().unwrap(),
+        vec![
+            Category::TvUhdEpisodes,
+            Category::TvHdEpisodes,
+            Category::TvEpisodes
+        ]
+    );
     assert_eq!(*parameters.sort_by(), SortBy::Seeders);
     // Default options
     assert!(*parameters.ranked());
@@ -32,6 +80,299 @@ async fn search_torrents_and_export_first_torrent() {
     let result = (api.list(Some(&parameters)).await).unwrap();
     let torrents = result.torrents();
     let torrent = torrents.first().unwrap();
-    let filepath = torrent.export(".").unwrap();
+    let filepath = torrent.export(temp_dir().to_str().unwrap()).unwrap();
     assert!(Path::new(filepath.as_str()).exists());
+    fs::remove_file(filepath).unwrap();
+
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.query("mode"), Some("list"));
+    assert_eq!(request.query("category"), Some("49;41;18"));
+    assert_eq!(request.query("sort"), Some("seeders"));
+}
+
+#[tokio::test]
+async fn refresh_expired_token_and_retry() {
+    let server = MockServer::start().await;
+    server.set_torrents(vec![sample_torrent(
+        "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    )]);
+    let api = builder(&server).build("integration-test").await.unwrap();
+
+    server.expire_tokens();
+    let result = api.search("Rick and Morty", None).await.unwrap();
+
+    assert_eq!(result.torrents().len(), 1);
+    assert_eq!(result.attempts(), 1);
+    assert_eq!(server.issued_tokens(), 2);
+    assert_eq!(api.token().value(), "mock-token-2");
+}
+
+#[tokio::test]
+async fn retry_when_rate_limited() {
+    let server = MockServer::start().await;
+    server.push(MockResponse::TooManyRequests);
+    server.push(MockResponse::Status(503, String::new()));
+    server.set_torrents(vec![sample_torrent(
+        "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    )]);
+    let api = builder(&server).build("integration-test").await.unwrap();
+
+    let result = api.list(None).await.unwrap();
+
+    assert_eq!(result.torrents().len(), 1);
+    assert_eq!(result.attempts(), 3);
+}
+
+#[tokio::test]
+async fn give_up_after_max_attempts() {
+    let server = MockServer::start().await;
+    for _ in 0..3 {
+        server.push(MockResponse::TooManyRequests);
+    }
+    let api = builder(&server).build("integration-test").await.unwrap();
+
+    let error = api.list(None).await.unwrap_err();
+
+    assert_eq!(error.attempts(), 3);
+    assert!(
+        matches!(error, RarBgError::Retried { last_error, .. } if matches!(*last_error, RarBgError::RateLimited))
+    );
+}
+
+#[tokio::test]
+async fn token_refresh_is_not_an_attempt() {
+    let server = MockServer::start().await;
+    server.push(MockResponse::TokenExpired);
+    server.push(MockResponse::Status(502, String::new()));
+    server.set_torrents(vec![sample_torrent(
+        "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    )]);
+    let api = builder(&server).build("integration-test").await.unwrap();
+
+    let result = api.list(None).await.unwrap();
+    assert_eq!(result.attempts(), 2);
+
+    server.push(MockResponse::TokenExpired);
+    for _ in 0..3 {
+        server.push(MockResponse::Status(503, String::new()));
+    }
+    let error = api.list(None).await.unwrap_err();
+    assert_eq!(error.attempts(), 3);
+    assert!(
+        matches!(error, RarBgError::Retried { last_error, .. } if matches!(*last_error, RarBgError::Http { status: 503, .. }))
+    );
+
+    server.push(MockResponse::TokenExpired);
+    server.push(MockResponse::Error(
+        ApiErrorCode::ImdbNotFound,
+        "Cant find imdb in database. Are you sure this imdb exists?".to_string(),
+    ));
+    match api.list(None).await {
+        Err(RarBgError::Api(error)) => assert_eq!(error.code(), ApiErrorCode::ImdbNotFound),
+        other => panic!("Expected an API error that is not retried, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn no_results_is_empty_or_error() {
+    let server = MockServer::start().await;
+    let api = builder(&server).build("integration-test").await.unwrap();
+    let result = api.search("Nothing", None).await.unwrap();
+    assert!(result.torrents().is_empty());
+
+    let api = builder(&server)
+        .no_results_as_empty(false)
+        .build("integration-test")
+        .await
+        .unwrap();
+    match api.search("Nothing", None).await {
+        Err(RarBgError::Api(error)) => assert_eq!(error.code(), ApiErrorCode::NoResults),
+        other => panic!("Expected a no results error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn fetch_token_with_builder_config() {
+    let server = MockServer::start().await;
+
+    let token = Token::with_builder(&builder(&server), "integration-test")
+        .await
+        .unwrap();
+
+    assert_eq!(token.value(), "mock-token-1");
+    assert_eq!(server.issued_tokens(), 1);
+}
+
+#[tokio::test]
+async fn reuse_stored_token() {
+    let server = MockServer::start().await;
+    let store = Arc::new(MemoryTokenStore::new());
+
+    let first = builder(&server)
+        .token_store(store.clone())
+        .build("integration-test")
+        .await
+        .unwrap();
+    let second = builder(&server)
+        .token_store(store)
+        .build("integration-test")
+        .await
+        .unwrap();
+
+    assert_eq!(server.issued_tokens(), 1);
+    assert_eq!(first.token(), second.token());
+}
+
+#[tokio::test]
+async fn replace_corrupt_token_file() {
+    let server = MockServer::start().await;
+    let path = temp_dir().join("rarbg_api_replace_corrupt_token_file.json");
+    fs::write(&path, "{\"integration-test\": ").unwrap();
+    let store = Arc::new(FileTokenStore::new(&path));
+
+    let api = builder(&server)
+        .token_store(store.clone())
+        .build("integration-test")
+        .await
+        .unwrap();
+
+    assert_eq!(server.issued_tokens(), 1);
+    assert_eq!(store.load("integration-test").unwrap(), Some(api.token()));
+    fs::remove_file(&path).unwrap();
+}
+
+#[derive(Debug)]
+struct ReadOnlyTokenStore;
+
+impl TokenStore for ReadOnlyTokenStore {
+    fn load(&self, _app_id: &str) -> Result<Option<Token>, RarBgError> {
+        Ok(None)
+    }
+
+    fn save(&self, _app_id: &str, _token: &Token) -> Result<(), RarBgError> {
+        Err(RarBgError::TokenStore("read-only".into()))
+    }
+}
+
+#[tokio::test]
+async fn keep_token_that_cannot_be_saved() {
+    let server = MockServer::start().await;
+    server.set_torrents(vec![sample_torrent(
+        "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+    )]);
+    let store = Arc::new(ReadOnlyTokenStore);
+    let api = builder(&server)
+        .token_store(store)
+        .build("integration-test")
+        .await
+        .unwrap();
+    assert_eq!(api.token().value(), "mock-token-1");
+
+    server.expire_tokens();
+    let result = api.search("Rick and Morty", None).await.unwrap();
+
+    assert_eq!(result.torrents().len(), 1);
+    assert_eq!(server.issued_tokens(), 2);
+    assert_eq!(api.token().value(), "mock-token-2");
+}
+
+#[tokio::test]
+async fn search_with_query() {
+    let server = MockServer::start().await;
+    server.set_torrents(vec![sample_torrent(
+        "Rick.and.Morty.S02E03.1080p.WEB.H264-CAKES",
+    )]);
+    let api = builder(&server).build("integration-test").await.unwrap();
+
+    let query = SearchQuery::new()
+        .with_id(SearchId::Imdb("tt2861424".parse().unwrap()))
+        .with_string("S02E03");
+    let result = api.query(&query, None).await.unwrap();
+    assert_eq!(result.torrents().len(), 1);
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.query("search_imdb"), Some("tt2861424"));
+    assert_eq!(request.query("search_string"), Some("S02E03"));
+
+    let sent = server.requests().len();
+    for query in [SearchQuery::new(), SearchQuery::new().with_string("  ")] {
+        match api.query(&query, None).await {
+            Err(RarBgError::InvalidQuery(_)) => {}
+            other => panic!("{:?} should be invalid, got {:?}", query, other),
+        }
+    }
+    assert_eq!(server.requests().len(), sent);
+}
+
+#[tokio::test]
+async fn search_episode_of_a_show() {
+    let server = MockServer::start().await;
+    let mut other_show = sample_torrent("Solar.Opposites.S03E07.1080p.WEB.H264-CAKES");
+    other_show["episode_info"]["imdb"] = "tt8910922".into();
+    server.set_torrents(vec![
+        sample_torrent("Rick.and.Morty.S03E07.1080p.WEB.H264-CAKES"),
+        sample_torrent("Rick.and.Morty.S03E08.1080p.WEB.H264-CAKES"),
+        sample_torrent("Rick.and.Morty.S03E06E07.720p.WEB.H264-CAKES"),
+        sample_torrent("Rick.and.Morty.S03.1080p.WEB.H264-CAKES"),
+        sample_torrent("Rick.and.Morty.S04E07.1080p.WEB.H264-CAKES"),
+        other_show,
+    ]);
+    let api = builder(&server).build("integration-test").await.unwrap();
+    let show = SearchId::Imdb("tt2861424".parse().unwrap());
+
+    let episode = api.search_episode(&show, 3, Some(7), None).await.unwrap();
+    let titles: Vec<&str> = episode
+        .torrents()
+        .iter()
+        .map(|torrent| torrent.title().unwrap().as_str())
+        .collect();
+    assert_eq!(
+        titles,
+        vec![
+            "Rick.and.Morty.S03E07.1080p.WEB.H264-CAKES",
+            "Rick.and.Morty.S03E06E07.720p.WEB.H264-CAKES",
+            "Rick.and.Morty.S03.1080p.WEB.H264-CAKES",
+        ]
+    );
+    let request = server.requests().pop().unwrap();
+    assert_eq!(request.query("search_imdb"), Some("tt2861424"));
+    assert_eq!(request.query("format"), Some("json_extended"));
+    assert_eq!(request.query("category"), Some("18;41;49"));
+    assert_eq!(request.query("search_string"), None);
+
+    let season = api.search_episode(&show, 3, None, None).await.unwrap();
+    assert_eq!(season.torrents().len(), 4);
+}
+
+#[tokio::test]
+async fn merge_results_keeps_freshest_counts() {
+    let server = MockServer::start().await;
+    let title = "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES";
+    let stale = SampleTorrentBuilder::new(title).seeders(Some(10)).json();
+    server.push(MockResponse::Torrents(vec![stale]));
+    let fresh = SampleTorrentBuilder::new(title).seeders(Some(42)).json();
+    server.push(MockResponse::Torrents(vec![
+        fresh,
+        sample_torrent("Rick.and.Morty.S06E02.1080p.WEB.H264-CAKES"),
+    ]));
+    let api = builder(&server).build("integration-test").await.unwrap();
+
+    let listed = api.list(None).await.unwrap();
+    let searched = api.search("Rick and Morty", None).await.unwrap();
+
+    let mut merged = searched.clone();
+    merged.merge(listed.clone());
+    assert_eq!(merged.torrents().len(), 2);
+    assert_eq!(merged.torrents()[0].seeders(), Some(&42));
+    assert_eq!(merged.attempts(), 2);
+
+    let mut merged = listed;
+    merged.merge(searched);
+    assert_eq!(merged.torrents().len(), 2);
+    assert_eq!(merged.torrents()[0].seeders(), Some(&42));
+}
+
+#[test]
+fn api_can_be_shared_between_tasks() {
+    fn assert_shareable<T: Send + Sync + Clone>() {}
+    assert_shareable::<RarBgApi>();
 }
@@ -0,0 +1,50 @@
+extern crate rarbg_api;
+
+use rarbg_api::magnet::Magnet;
+
+#[test]
+fn parse_info_hash_forms() {
+    let hex: Magnet = "magnet:?xt=urn:btih:3F5E8D1E4C7A0B6F2D9E1C8B7A6F5E4D3C2B1A09"
+        .parse()
+        .unwrap();
+    let base32: Magnet = format!(
+        "magnet:?xt=urn:btih:{}",
+        hex.info_hash_base32().to_lowercase()
+    )
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        hex.info_hash_hex(),
+        "3f5e8d1e4c7a0b6f2d9e1c8b7a6f5e4d3c2b1a09"
+    );
+    assert_eq!(base32.info_hash(), hex.info_hash());
+}
+
+#[test]
+fn reject_non_ascii_info_hash() {
+    let hex = format!("magnet:?xt=urn:btih:a\u{e9}{}", "a".repeat(37));
+    let base32 = format!("magnet:?xt=urn:btih:\u{e9}{}", "A".repeat(30));
+
+    assert!(hex.parse::<Magnet>().is_err());
+    assert!(base32.parse::<Magnet>().is_err());
+    assert!("magnet:?xt=urn:bt\u{e9}h:3f5e".parse::<Magnet>().is_err());
+}
+
+#[test]
+fn skip_xt_that_is_not_btih() {
+    let hybrid: Magnet =
+        "magnet:?xt=urn:btmh:1220caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e\
+        &xt=urn:btih:3f5e8d1e4c7a0b6f2d9e1c8b7a6f5e4d3c2b1a09&dn=Hybrid"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        hybrid.info_hash_hex(),
+        "3f5e8d1e4c7a0b6f2d9e1c8b7a6f5e4d3c2b1a09"
+    );
+    assert_eq!(hybrid.display_name().unwrap(), "Hybrid");
+
+    let v2_only =
+        "magnet:?xt=urn:btmh:1220caf1e1c30e81cb361b9ee167c4aa64228a7fa4fa9f6105232b28ad099f3a302e";
+    assert!(v2_only.parse::<Magnet>().is_err());
+}
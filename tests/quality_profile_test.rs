@@ -0,0 +1,151 @@
+mod common;
+
+use rarbg_api::category::Category;
+use rarbg_api::quality_profile::QualityProfile;
+use rarbg_api::quality_profile_builder::QualityProfileBuilder;
+use rarbg_api::resolution::Resolution;
+use rarbg_api::source::Source;
+use rarbg_api::torrent::Torrent;
+use rarbg_api::torrents::Torrents;
+use rarbg_api::video_codec::VideoCodec;
+
+use common::SampleTorrentBuilder;
+
+fn torrent(title: &str, category: &str, seeders: u32, size: u64) -> Torrent {
+    SampleTorrentBuilder::new(title)
+        .category(category)
+        .seeders(Some(seeders))
+        .size(size)
+        .build()
+}
+
+#[test]
+fn explain_best_match() {
+    let profile = QualityProfileBuilder::new()
+        .category(Category::MoviesX2654kHdr, -20)
+        .resolution(Resolution::FullHd1080, 80)
+        .resolution(Resolution::UltraHd2160, 100)
+        .source(Source::BluRay, 30)
+        .video_codec(VideoCodec::H265, 10)
+        .max_size(10_000_000_000)
+        .min_seeders(5)
+        .preferred_word("REPACK", 5)
+        .forbidden_word("HDCAM")
+        .build();
+    let torrents = Torrents::new(vec![
+        torrent(
+            "Movie.2022.HDCAM.1080p.x264-NOPE",
+            "Movies/x264/1080",
+            999,
+            1_000_000_000,
+        ),
+        torrent(
+            "Movie.2022.1080p.BluRay.x264-GROUP",
+            "Movies/x264/1080",
+            2,
+            8_000_000_000,
+        ),
+        torrent(
+            "Movie.2022.1080p.BluRay.REMUX.x265-GROUP",
+            "Movies/BD Remux",
+            50,
+            30_000_000_000,
+        ),
+        torrent(
+            "Movie.2022.2160p.BluRay.x265.HDR-GROUP",
+            "Movies/x265/4k/HDR",
+            99,
+            9_000_000_000,
+        ),
+        torrent(
+            "Movie.2022.REPACK.1080p.WEB.x265-GROUP",
+            "Movies/x265/1080",
+            9,
+            2_000_000_000,
+        ),
+    ]);
+
+    let rejections: Vec<usize> = torrents
+        .torrents()
+        .iter()
+        .map(|torrent| profile.score(torrent).rejections().len())
+        .collect();
+    assert_eq!(rejections, vec![1, 1, 1, 0, 0]);
+
+    let (best, breakdown) = torrents.best_match(&profile).unwrap();
+    assert_eq!(
+        best.title().unwrap(),
+        "Movie.2022.2160p.BluRay.x265.HDR-GROUP"
+    );
+    let entries: Vec<(&str, i32)> = breakdown
+        .entries()
+        .iter()
+        .map(|entry| (entry.reason(), entry.points()))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("category MoviesX2654kHdr", -20),
+            ("resolution 2160p", 100),
+            ("source BluRay", 30),
+            ("video codec H265", 10),
+            ("99 seeders", 20),
+        ]
+    );
+    assert_eq!(breakdown.total(), 140);
+
+    let json = serde_json::to_string(&profile).unwrap();
+    assert_eq!(
+        serde_json::from_str::<QualityProfile>(&json).unwrap(),
+        profile
+    );
+
+    let forbidding = QualityProfileBuilder::new().forbidden_word("movie").build();
+    assert!(torrents.best_match(&forbidding).is_none());
+}
+
+#[test]
+fn match_whole_words() {
+    let profile = QualityProfileBuilder::new()
+        .preferred_word("web-dl", 10)
+        .preferred_word("GROUP", 5)
+        .forbidden_word("CAM")
+        .build();
+
+    let scam = SampleTorrentBuilder::new("Scam.City.2021.1080p.WEB-DL.x264-GROUP").build();
+    let breakdown = profile.score(&scam);
+    assert!(!breakdown.is_rejected());
+    let reasons: Vec<&str> = breakdown
+        .entries()
+        .iter()
+        .map(|entry| entry.reason())
+        .collect();
+    assert!(reasons.contains(&"preferred word \"web-dl\""));
+    assert!(reasons.contains(&"preferred word \"GROUP\""));
+
+    let cam = SampleTorrentBuilder::new("Movie.2022.CAM.x264-GROUPIE").build();
+    let breakdown = profile.score(&cam);
+    assert!(breakdown.is_rejected());
+    assert!(breakdown
+        .entries()
+        .iter()
+        .all(|entry| !entry.reason().starts_with("preferred word")));
+}
+
+#[test]
+fn saturate_total() {
+    let profile = QualityProfileBuilder::new()
+        .preferred_word("Movie", i32::MAX)
+        .preferred_word("2022", i32::MAX)
+        .build();
+    let torrent = SampleTorrentBuilder::new("Movie.2022.1080p.WEB.x264-GROUP").build();
+
+    assert_eq!(profile.score(&torrent).total(), i32::MAX);
+
+    let profile = QualityProfileBuilder::new()
+        .preferred_word("Movie", i32::MIN)
+        .preferred_word("2022", i32::MIN)
+        .seeders_weight(0.0)
+        .build();
+    assert_eq!(profile.score(&torrent).total(), i32::MIN);
+}
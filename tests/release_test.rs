@@ -0,0 +1,113 @@
+use rarbg_api::audio::Audio;
+use rarbg_api::episode_code::EpisodeCode;
+use rarbg_api::hdr::Hdr;
+use rarbg_api::release;
+use rarbg_api::resolution::Resolution;
+use rarbg_api::source::Source;
+use rarbg_api::video_codec::VideoCodec;
+
+#[test]
+fn parse_tv_releases() {
+    let release =
+        release::parse("The.Expanse.S05E03.REPACK.2160p.AMZN.WEB-DL.DDP5.1.HDR.HEVC-NTb[rartv]");
+    assert_eq!(release.name(), "The Expanse");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::new(5, Some(3))));
+    assert_eq!(release.resolution(), Some(&Resolution::UltraHd2160));
+    assert_eq!(release.source(), Some(&Source::WebDl));
+    assert_eq!(release.video_codec(), Some(&VideoCodec::H265));
+    assert_eq!(release.hdr(), &vec![Hdr::Hdr10]);
+    assert_eq!(release.audio(), &vec![Audio::Eac3]);
+    assert_eq!(release.group().map(|group| group.as_str()), Some("NTb"));
+    assert!(release.repack());
+    assert!(!release.proper());
+
+    let release = release::parse("Doctor.Who.2005.S13E01.720p.HDTV.x264-SHORTBREHD");
+    assert_eq!(release.name(), "Doctor Who");
+    assert_eq!(release.year(), Some(&2005));
+    assert_eq!(release.source(), Some(&Source::Hdtv));
+
+    let release = release::parse("Rick and Morty Season 6 Complete 1080p WEBRip H.264 AAC");
+    assert_eq!(release.name(), "Rick and Morty");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::new(6, None)));
+    assert_eq!(release.source(), Some(&Source::WebRip));
+    assert_eq!(release.video_codec(), Some(&VideoCodec::H264));
+    assert_eq!(release.audio(), &vec![Audio::Aac]);
+    assert_eq!(release.group(), None);
+
+    let release = release::parse("The.Real.Housewives.of.Atlanta.S15E01.1080p.WEB.h264-BAE");
+    assert_eq!(release.name(), "The Real Housewives of Atlanta");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::new(15, Some(1))));
+    assert!(!release.proper());
+
+    let release = release::parse("The.Real.Housewives.of.Atlanta.S15E01.REAL.720p.HDTV.x264-BAE");
+    assert_eq!(release.name(), "The Real Housewives of Atlanta");
+    assert!(release.proper());
+
+    let release = release::parse("Limited.Edition.S01E02.INTERNAL.720p.WEB.H264-GROUP");
+    assert_eq!(release.name(), "Limited Edition");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::new(1, Some(2))));
+}
+
+#[test]
+fn parse_movie_releases() {
+    let release =
+        release::parse("2001.A.Space.Odyssey.1968.1080p.BluRay.TrueHD.Atmos.7.1.DV.x265-GROUP");
+    assert_eq!(release.name(), "2001 A Space Odyssey");
+    assert_eq!(release.year(), Some(&1968));
+    assert_eq!(release.episode_code(), None);
+    assert_eq!(release.source(), Some(&Source::BluRay));
+    assert_eq!(release.hdr(), &vec![Hdr::DolbyVision]);
+    assert_eq!(release.audio(), &vec![Audio::TrueHd, Audio::Atmos]);
+
+    let release = release::parse("Unknown");
+    assert_eq!(release.name(), "Unknown");
+    assert_eq!(release.resolution(), None);
+    assert_eq!(release.group(), None);
+
+    let release = release::parse("The.Complete.Guide.to.Everything.2020.1080p.WEB.H264-GROUP");
+    assert_eq!(release.name(), "The Complete Guide to Everything");
+    assert_eq!(release.year(), Some(&2020));
+    assert_eq!(release.resolution(), Some(&Resolution::FullHd1080));
+
+    let release = release::parse("Blade.Runner.1982.The.Final.Cut.REMASTERED.1080p.BluRay.x264");
+    assert_eq!(release.name(), "Blade Runner");
+    assert_eq!(release.year(), Some(&1982));
+    assert_eq!(release.source(), Some(&Source::BluRay));
+}
+
+#[test]
+fn parse_episode_codes() {
+    let release = release::parse("Show.2x05.720p-X");
+    assert_eq!(release.name(), "Show");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::new(2, Some(5))));
+    assert_eq!(release.group().map(|group| group.as_str()), Some("X"));
+
+    let release = release::parse("Show.S01E01E02E03.720p-X");
+    assert_eq!(release.name(), "Show");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::range(1, 1, 3)));
+
+    let release = release::parse("Show.S01E01-E02.720p-X");
+    assert_eq!(release.episode_code(), Some(&EpisodeCode::range(1, 1, 2)));
+
+    let release = release::parse("Movie.1920x1080.x264-X");
+    assert_eq!(release.episode_code(), None);
+
+    for (code, expected) in [
+        ("S01E01E02E03", EpisodeCode::range(1, 1, 3)),
+        ("s01e01-e02-e04", EpisodeCode::range(1, 1, 4)),
+        ("3x07-08", EpisodeCode::range(3, 7, 8)),
+    ]
+    .iter()
+    {
+        assert_eq!(code.parse::<EpisodeCode>().unwrap(), *expected);
+    }
+    for code in ["S01E03E02", "S01E01E", "1920x1080"].iter() {
+        assert!(code.parse::<EpisodeCode>().is_err(), "{}", code);
+    }
+}
+
+#[test]
+fn keep_first_video_codec() {
+    let release = release::parse("Movie.2022.x264.H.265-G");
+    assert_eq!(release.video_codec(), Some(&VideoCodec::H264));
+}
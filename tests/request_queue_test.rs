@@ -0,0 +1,76 @@
+extern crate rarbg_api;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rarbg_api::priority::Priority;
+use rarbg_api::request_queue::RequestQueue;
+
+async fn wait_for_waiters(queue: &RequestQueue, count: usize) {
+    while queue.len() < count {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+}
+
+#[tokio::test]
+async fn queued_requests_are_let_through_by_priority() {
+    let queue = Arc::new(RequestQueue::new());
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let permit = queue.acquire(Priority::Normal).await;
+
+    let mut handles = Vec::new();
+    for (count, priority) in [
+        Priority::Background,
+        Priority::Normal,
+        Priority::Interactive,
+        Priority::Normal,
+    ]
+    .iter()
+    .enumerate()
+    {
+        let task_queue = queue.clone();
+        let order = order.clone();
+        let priority = *priority;
+        handles.push(tokio::spawn(async move {
+            let _permit = task_queue.acquire(priority).await;
+            order.lock().unwrap().push((priority, count));
+        }));
+        wait_for_waiters(&queue, count + 1).await;
+    }
+    drop(permit);
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec![
+            (Priority::Interactive, 2),
+            (Priority::Normal, 1),
+            (Priority::Normal, 3),
+            (Priority::Background, 0)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn dropped_requests_leave_the_queue() {
+    let queue = Arc::new(RequestQueue::new());
+    let permit = queue.acquire(Priority::Normal).await;
+
+    let cancelled = {
+        let queue = queue.clone();
+        tokio::spawn(async move {
+            let _permit = queue.acquire(Priority::Interactive).await;
+        })
+    };
+    wait_for_waiters(&queue, 1).await;
+    cancelled.abort();
+    assert!(cancelled.await.unwrap_err().is_cancelled());
+    assert!(queue.is_empty());
+
+    drop(permit);
+    let next =
+        tokio::time::timeout(Duration::from_secs(1), queue.acquire(Priority::Background)).await;
+    assert!(next.is_ok());
+}
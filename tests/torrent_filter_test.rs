@@ -0,0 +1,74 @@
+mod common;
+
+use rarbg_api::resolution::Resolution;
+use rarbg_api::torrent_filter::TorrentFilter;
+use rarbg_api::torrents::Torrents;
+
+use common::{titles, SampleTorrentBuilder};
+
+fn torrents() -> Torrents {
+    Torrents::new(vec![
+        SampleTorrentBuilder::new("Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES").build(),
+        SampleTorrentBuilder::new("Rick.and.Morty.S06E01.720p.HDTV.x264-SYNCOPY")
+            .size(300_000_000)
+            .build(),
+        SampleTorrentBuilder::new("Rick.and.Morty.S06E01.2160p.WEB.H265-CAKES")
+            .pub_date("2015-01-01 00:00:00 +0000")
+            .build(),
+        SampleTorrentBuilder::new("Rick.and.Morty.S06E01.1080p.WEB.x265-YIFY").build(),
+    ])
+}
+
+#[test]
+fn filter_from_config_file() {
+    let config = r#"{
+        "all": [
+            { "size": { "min": 500000000, "max": null } },
+            { "published": { "after": "2020-01-01T00:00:00Z", "before": null } },
+            { "not": { "exclude_groups": ["cakes"] } },
+            { "any": [{ "video_codecs": ["H264"] }, { "title_matches": "(?i)hdr" }] }
+        ]
+    }"#;
+    let filter: TorrentFilter = serde_json::from_str(config).unwrap();
+
+    let selected = torrents().filter(&filter);
+
+    assert_eq!(
+        titles(&selected),
+        vec!["Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES"]
+    );
+    assert!(serde_json::from_str::<TorrentFilter>(r#"{ "title_matches": "(" }"#).is_err());
+}
+
+#[test]
+fn combine_filters() {
+    let mut torrents = torrents();
+    let filter = TorrentFilter::MinResolution(Resolution::FullHd1080)
+        .and(TorrentFilter::TitleExcludes("yify".to_string()))
+        .or(TorrentFilter::Groups(vec!["SYNCOPY".to_string()]));
+
+    torrents.retain(&filter);
+
+    assert_eq!(
+        titles(&torrents),
+        vec![
+            "Rick.and.Morty.S06E01.1080p.WEB.H264-CAKES",
+            "Rick.and.Morty.S06E01.720p.HDTV.x264-SYNCOPY",
+            "Rick.and.Morty.S06E01.2160p.WEB.H265-CAKES",
+        ]
+    );
+    assert_eq!(!!filter.clone(), filter);
+}
+
+#[test]
+fn published_within_out_of_range_has_no_limit() {
+    let filter: TorrentFilter =
+        serde_json::from_str(r#"{ "published_within": 9223372036854775807 }"#).unwrap();
+    assert_eq!(torrents().filter(&filter).torrents().len(), 4);
+
+    let filter = TorrentFilter::PublishedWithin(i64::MIN);
+    assert_eq!(torrents().filter(&filter).torrents().len(), 4);
+
+    let filter = TorrentFilter::PublishedWithin(3600);
+    assert!(torrents().filter(&filter).torrents().is_empty());
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use rarbg_api::sort_key::SortKey;
+use rarbg_api::sort_order::SortOrder;
+use rarbg_api::torrent::Torrent;
+use rarbg_api::torrent_sort::TorrentSort;
+use rarbg_api::torrents::Torrents;
+
+use common::{titles, SampleTorrentBuilder};
+
+fn torrent(title: &str, seeders: Option<u32>, leechers: u32, size: u64) -> Torrent {
+    SampleTorrentBuilder::new(title)
+        .seeders(seeders)
+        .leechers(Some(leechers))
+        .size(size)
+        .build()
+}
+
+#[test]
+fn sort_by_several_keys() {
+    let mut torrents = Torrents::new(vec![
+        torrent("A", Some(10), 5, 300),
+        torrent("B", None, 5, 100),
+        torrent("C", Some(20), 1, 200),
+        torrent("D", Some(10), 5, 100),
+        torrent("E", Some(10), 5, 100),
+    ]);
+
+    torrents.sort(
+        &TorrentSort::new()
+            .then(SortKey::Seeders, SortOrder::Descending)
+            .then(SortKey::Size, SortOrder::Ascending),
+    );
+    assert_eq!(titles(&torrents), vec!["C", "D", "E", "A", "B"]);
+
+    torrents.sort_by_key(SortKey::Seeders, SortOrder::Ascending);
+    assert_eq!(titles(&torrents), vec!["D", "E", "A", "C", "B"]);
+
+    torrents.sort_by_key(SortKey::Ratio, SortOrder::Descending);
+    assert_eq!(titles(&torrents), vec!["C", "D", "E", "A", "B"]);
+}
+
+#[test]
+fn sort_by_score() {
+    let mut torrents = Torrents::new(vec![
+        torrent("Show.S01E01.720p.WEB.H264-GROUP", Some(50), 0, 100),
+        torrent("Show.S01E01.2160p.WEB.H265-GROUP", Some(5), 0, 100),
+        torrent("Show.S01E01.1080p.WEB.H264-GROUP", Some(20), 0, 100),
+        torrent("Show.S01E01.XviD-GROUP", Some(90), 0, 100),
+    ]);
+
+    torrents.sort(
+        &TorrentSort::new()
+            .then_score(
+                |torrent| match torrent.title() {
+                    Some(title) if title.contains("1080p") => 2.0,
+                    Some(title) if title.contains("2160p") => 1.0,
+                    Some(title) if title.contains("p.") => 0.0,
+                    _ => f64::NAN,
+                },
+                SortOrder::Descending,
+            )
+            .then(SortKey::Seeders, SortOrder::Descending),
+    );
+
+    assert_eq!(
+        titles(&torrents),
+        vec![
+            "Show.S01E01.1080p.WEB.H264-GROUP",
+            "Show.S01E01.2160p.WEB.H265-GROUP",
+            "Show.S01E01.720p.WEB.H264-GROUP",
+            "Show.S01E01.XviD-GROUP",
+        ]
+    );
+}